@@ -0,0 +1,53 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Generate the registry of the solvers from the files of `src/solvers`: each `y<year>`
+/// directory is a year, and each `dayNN.rs` file in it is the module of a day.
+fn main() -> io::Result<()> {
+    let solvers_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src").join("solvers");
+    println!("cargo:rerun-if-changed={}", solvers_dir.display());
+
+    let mut years: Vec<(u16, PathBuf)> = Vec::new();
+    for entry in fs::read_dir(&solvers_dir)? {
+        let path = entry?.path();
+        let year = path.file_name()
+            .and_then(|name| name.to_str()?.strip_prefix('y')?.parse().ok());
+        if let (Some(year), true) = (year, path.is_dir()) {
+            years.push((year, path));
+        }
+    }
+    years.sort();
+
+    let mut registry = String::new();
+    for (year, year_dir) in &years {
+        let mut days: Vec<String> = Vec::new();
+        for entry in fs::read_dir(year_dir)? {
+            let path = entry?.path();
+            let day = path.file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day")?.strip_suffix(".rs"))
+                .filter(|day| day.len() == 2 && day.chars().all(|c| c.is_ascii_digit()));
+            if let Some(day) = day {
+                days.push(day.to_string());
+            }
+        }
+        days.sort();
+
+        registry += &format!("pub mod y{} {{\n    pub const YEAR: u16 = {};\n\n", year, year);
+        for day in &days {
+            // Modules declared in an included file are relative to it, so their paths are absolute
+            let path = year_dir.join(format!("day{}.rs", day));
+            registry += &format!("    #[path = {:?}]\n    pub mod day{};\n", path.display().to_string(), day);
+        }
+
+        let puzzles: Vec<String> = days.iter().map(|day| format!("day{}::PUZZLE", day)).collect();
+        registry += &format!("\n    pub const PUZZLES: &[crate::Puzzle] = &[{}];\n}}\n\n", puzzles.join(", "));
+    }
+
+    let entries: Vec<String> = years.iter().map(|(year, _)| format!("(y{0}::YEAR, y{0}::PUZZLES)", year)).collect();
+    registry += "/// Puzzles of every registered year, in order.\n";
+    registry += &format!("pub const YEARS: &[(u16, &[crate::Puzzle])] = &[{}];\n", entries.join(", "));
+
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("solvers.rs"), registry)
+}
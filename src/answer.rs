@@ -0,0 +1,88 @@
+use std::fmt::{self, Display, Formatter};
+use serde::Serialize;
+use crate::helpers;
+
+/// Answer of a puzzle part, as it is submitted on the website.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i64),
+    /// Integer that doesn't fit in an `i64`.
+    BigInteger(i128),
+    Text(String),
+    /// Rows of an ASCII-art drawing, usually letters to read.
+    Grid(Vec<String>),
+    /// The part has no puzzle, like the second part of the last day.
+    NotApplicable,
+    /// The input has no solution, the puzzle can't be solved.
+    NoSolution,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+            Answer::NotApplicable => write!(f, "n/a"),
+            Answer::NoSolution => write!(f, "no solution"),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($type:ty),*) => {
+        $(impl From<$type> for Answer {
+            fn from(value: $type) -> Answer {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+macro_rules! from_big_integer {
+    ($($type:ty),*) => {
+        $(impl From<$type> for Answer {
+            fn from(value: $type) -> Answer {
+                match i64::try_from(value) {
+                    Ok(value) => Answer::Integer(value),
+                    Err(_) => Answer::BigInteger(value as i128),
+                }
+            }
+        })*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, u8, u16, u32);
+from_big_integer!(u64, usize, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<&helpers::Map<char>> for Answer {
+    fn from(map: &helpers::Map<char>) -> Answer {
+        Answer::Grid(map.rows().map(|row| row.iter().collect()).collect())
+    }
+}
+
+/// A missing value means that the input has no solution.
+impl<T> From<Option<T>> for Answer
+    where T: Into<Answer>
+{
+    fn from(value: Option<T>) -> Answer {
+        value.map_or(Answer::NoSolution, Into::into)
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use serde::Serialize;
+use crate::Answer;
+use crate::error::Error;
+use crate::inputs::DEFAULT_INPUT;
+
+/// Result of the comparison between an answer and the expected one.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// Expected answers of the two parts of a puzzle input.
+#[derive(Debug)]
+#[derive(Default)]
+pub struct InputAnswers {
+    parts: [Option<String>; 2],
+}
+
+impl InputAnswers {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 | 2 => self.parts[part as usize - 1].as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Compare an answer with the expected one, ignoring surrounding whitespace.
+    /// Nothing is expected from a part without puzzle.
+    pub fn check(&self, part: u8, answer: &Answer) -> Verdict {
+        match (self.expected(part), answer) {
+            (None, Answer::NotApplicable) => Verdict::Pass,
+            (None, _) => Verdict::Unknown,
+            (Some(expected), _) if expected.trim() == answer.to_string().trim() => Verdict::Pass,
+            (Some(_), _) => Verdict::Fail,
+        }
+    }
+}
+
+static NO_ANSWERS: InputAnswers = InputAnswers { parts: [None, None] };
+
+/// Expected answers of the puzzles, stored as a TOML file with a table per day for the
+/// default input and a sub-table per named input:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1162
+/// part2 = 1190
+///
+/// [day01.example]
+/// part1 = 7
+/// part2 = 5
+/// ```
+///
+/// A day being solved is marked with `pending = true` until its first answer is saved.
+#[derive(Debug)]
+#[derive(Default)]
+pub struct Answers {
+    inputs: HashMap<(u8, String), InputAnswers>,
+    pending: HashSet<u8>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers, Error> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Answers of a named input of a day, none are expected for unknown inputs.
+    pub fn get(&self, day: u8, input: &str) -> &InputAnswers {
+        self.inputs.get(&(day, input.to_string())).unwrap_or(&NO_ANSWERS)
+    }
+
+    pub fn expected(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        self.get(day, input).expected(part)
+    }
+
+    pub fn check(&self, day: u8, input: &str, part: u8, answer: &Answer) -> Verdict {
+        self.get(day, input).check(part, answer)
+    }
+
+    pub fn is_pending(&self, day: u8) -> bool {
+        self.pending.contains(&day)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Answers, Error> {
+        let table: toml::Table = content.parse().map_err(|error: toml::de::Error| Error::InvalidAnswers(error.to_string().trim_end().to_string()))?;
+
+        let mut inputs = HashMap::new();
+        let mut pending = HashSet::new();
+        for (day_key, entries) in table {
+            let day = day_key.strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| Error::InvalidAnswers(format!("'{}' is not a day, expected dayNN", day_key)))?;
+            let entries = entries.as_table()
+                .ok_or_else(|| Error::InvalidAnswers(format!("'{}' is not a table", day_key)))?;
+
+            let mut default_answers = InputAnswers::default();
+            for (key, value) in entries {
+                match value.as_table() {
+                    None if key == "pending" => match value.as_bool() {
+                        Some(true) => {
+                            pending.insert(day);
+                        },
+                        Some(false) => (),
+                        None => return Err(Error::InvalidAnswers(format!("'{}.pending' must be a boolean", day_key))),
+                    },
+                    Some(parts) => {
+                        let mut answers = InputAnswers::default();
+                        for (part_key, value) in parts {
+                            set_answer(&mut answers, &format!("{}.{}.{}", day_key, key, part_key), part_key, value)?;
+                        }
+                        inputs.insert((day, key.clone()), answers);
+                    },
+                    None => set_answer(&mut default_answers, &format!("{}.{}", day_key, key), key, value)?,
+                }
+            }
+            inputs.insert((day, DEFAULT_INPUT.to_string()), default_answers);
+        }
+
+        Ok(Answers { inputs, pending })
+    }
+}
+
+/// Add the answer of a part of the default input to an answers file, keeping its formatting.
+/// Integer answers are stored as integers, the others as strings. The day is no longer pending.
+pub fn save_answer(path: impl AsRef<Path>, day: u8, part: u8, answer: &str) -> Result<(), Error> {
+    let answer = answer.trim();
+    edit_day(path.as_ref(), day, |table| {
+        table[&format!("part{}", part)] = match answer.parse::<i64>() {
+            Ok(answer) => toml_edit::value(answer),
+            Err(_) => toml_edit::value(answer),
+        };
+        table.remove("pending");
+    })
+}
+
+/// Mark a day of an answers file as pending, until an answer is saved.
+pub fn save_pending(path: impl AsRef<Path>, day: u8) -> Result<(), Error> {
+    edit_day(path.as_ref(), day, |table| {
+        table["pending"] = toml_edit::value(true);
+    })
+}
+
+/// Edit the table of a day in an answers file, which is created when missing.
+fn edit_day(path: &Path, day: u8, edit: impl FnOnce(&mut toml_edit::Table)) -> Result<(), Error> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+    let mut document: toml_edit::DocumentMut = content.parse().map_err(|error: toml_edit::TomlError| Error::InvalidAnswers(error.to_string().trim_end().to_string()))?;
+
+    let day_key = format!("day{:02}", day);
+    let table = document.entry(&day_key).or_insert(toml_edit::table()).as_table_mut()
+        .ok_or_else(|| Error::InvalidAnswers(format!("'{}' is not a table", day_key)))?;
+    edit(table);
+
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+fn set_answer(answers: &mut InputAnswers, path: &str, part_key: &str, value: &toml::Value) -> Result<(), Error> {
+    let part = match part_key {
+        "part1" => 1,
+        "part2" => 2,
+        _ => return Err(Error::InvalidAnswers(format!("'{}' is not a part, expected part1 or part2", path))),
+    };
+    let answer = match value {
+        toml::Value::Integer(answer) => answer.to_string(),
+        toml::Value::String(answer) => answer.clone(),
+        _ => return Err(Error::InvalidAnswers(format!("'{}' must be an integer or a string", path))),
+    };
+    answers.parts[part - 1] = Some(answer);
+    Ok(())
+}
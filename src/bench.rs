@@ -0,0 +1,170 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use aoc::{get_solvers, Solver};
+use aoc::error::Error;
+use crate::{fmt_dur, runner};
+
+/// Statistics over the runs of a phase, in seconds.
+#[derive(Copy, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from(times: &[Duration]) -> Stats {
+        let mut times: Vec<f64> = times.iter().map(Duration::as_secs_f64).collect();
+        times.sort_by(f64::total_cmp);
+
+        let count = times.len() as f64;
+        let mid = times.len() / 2;
+        let median = if times.len() % 2 == 0 { (times[mid - 1] + times[mid]) / 2.0 } else { times[mid] };
+        let mean = times.iter().sum::<f64>() / count;
+        let variance = if times.len() > 1 {
+            times.iter().map(|time| (time - mean).powi(2)).sum::<f64>() / (count - 1.0)
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: times[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Benchmark of one phase (parse, part1 or part2) of a day.
+#[derive(Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub phase: String,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+/// Run the parser and both parts of a day `warmup` times without measuring, then `runs` times.
+/// The input is read once beforehand so that only parsing is measured. Each phase runs on its own
+/// thread within the time budget like with `run`: a timeout or a panic stops the benchmark of the day.
+pub fn bench_day(year: u16, day: u8, input_name: &str, path: &Path, warmup: usize, runs: usize, timeout: Option<Duration>) -> Result<Vec<Record>, Error> {
+    let input = Arc::new(fs::read(path)?);
+
+    let mut times: [Vec<Duration>; 3] = Default::default();
+    for run in 0..warmup + runs {
+        let mut durations = [Duration::ZERO; 3];
+
+        let mut solver = get_solvers(year, day)?;
+        let parse_input = Arc::clone(&input);
+        let (parsed, time) = runner::run_step(timeout, move || solver.parse(&mut parse_input.as_slice()).map(|_| solver));
+        let solver: Arc<dyn Solver> = Arc::from(parsed?);
+        durations[0] = time;
+
+        for part in 1..=2 {
+            let solved = runner::solve_part(&solver, part, timeout);
+            solved.answer?;
+            durations[part as usize] = solved.time;
+        }
+
+        if run >= warmup {
+            for (times, duration) in times.iter_mut().zip(durations) {
+                times.push(duration);
+            }
+        }
+    }
+
+    Ok(["parse", "part1", "part2"].iter().zip(times).map(|(phase, times)| Record {
+        year,
+        day,
+        input: input_name.to_string(),
+        phase: phase.to_string(),
+        runs,
+        stats: Stats::from(&times),
+    }).collect())
+}
+
+/// Saved benchmarks live next to the build artifacts, like the ones of criterion.
+pub fn baseline_path(name: &str) -> PathBuf {
+    Path::new("target").join("bench").join(format!("{}.json", name))
+}
+
+pub fn load_baseline(name: &str) -> Result<Vec<Record>, Error> {
+    let content = fs::read_to_string(baseline_path(name))?;
+    Ok(serde_json::from_str(&content).map_err(io::Error::from)?)
+}
+
+/// Save the records under the given name, keeping the saved records of the days that were not benchmarked.
+pub fn save_baseline(name: &str, records: &[Record]) -> Result<(), Error> {
+    let mut saved: Vec<Record> = match load_baseline(name) {
+        Ok(saved) => saved,
+        Err(Error::Io(error)) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(error) => return Err(error),
+    };
+    saved.retain(|record| records.iter().all(|new| (new.year, new.day, &new.input) != (record.year, record.day, &record.input)));
+    saved.extend(records.iter().cloned());
+    saved.sort_by(|a, b| (a.year, a.day, &a.input).cmp(&(b.year, b.day, &b.input)));
+
+    let path = baseline_path(name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&saved).map_err(io::Error::from)?)?;
+    Ok(())
+}
+
+/// Change of the median against the baseline, negative when faster.
+fn change_cell(record: &Record, baseline: &[Record]) -> String {
+    match baseline.iter().find(|base| base.year == record.year && base.day == record.day && base.input == record.input && base.phase == record.phase) {
+        Some(base) if base.stats.median > 0.0 => {
+            let change = (record.stats.median - base.stats.median) / base.stats.median * 100.0;
+            format!("{:+.1}%", change)
+        },
+        _ => String::from("-"),
+    }
+}
+
+pub fn print_table(records: &[Record], baseline: &[Record]) {
+    let secs = |secs: f64| fmt_dur(Duration::from_secs_f64(secs));
+
+    let header = ["Day", "Phase", "Min", "Median", "Mean", "Std dev", "Change"].map(String::from);
+    let mut rows: Vec<[String; 7]> = Vec::new();
+    for record in records {
+        rows.push([
+            record.day.to_string(),
+            record.phase.clone(),
+            secs(record.stats.min),
+            secs(record.stats.median),
+            secs(record.stats.mean),
+            secs(record.stats.stddev),
+            change_cell(record, baseline),
+        ]);
+    }
+
+    let mut widths = [0usize; 7];
+    for row in [&header].into_iter().chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &[String; 7]| {
+        let cells: Vec<String> = row.iter().zip(widths.iter()).enumerate().map(|(i, (cell, &width))| {
+            if i == 1 { format!("{:<width$}", cell) } else { format!("{:>width$}", cell) }
+        }).collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+
+    print_row(&header);
+    println!("{}", "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1)));
+    rows.iter().for_each(print_row);
+}
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+use std::time::Duration;
+use clap::{Parser, Subcommand, ValueEnum};
+use aoc::client::DEFAULT_BASE_URL;
+use aoc::inputs::DEFAULT_INPUT;
+
+#[derive(Parser)]
+#[command(name = "aoc", version, about = "Solve the Advent of Code puzzles")]
+pub struct Cli {
+    /// Year of the event [default: the latest registered year]
+    #[arg(short, long, global = true)]
+    pub year: Option<u16>,
+
+    /// Named input of the days, read from data/<year>/dayNN/<name>.txt
+    #[arg(long, env = "AOC_VARIANT", default_value = DEFAULT_INPUT, global = true)]
+    pub variant: String,
+
+    /// Website the missing inputs are downloaded from, with the session token of AOC_SESSION or .aoc-session
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL, global = true)]
+    pub base_url: String,
+
+    /// Time budget of each part in seconds, the parts that take longer are reported as TIMEOUT
+    #[arg(long, env = "AOC_TIMEOUT", value_name = "SECONDS", value_parser = parse_seconds, global = true)]
+    pub timeout: Option<Duration>,
+
+    /// Only print the answers
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text, global = true)]
+    pub format: Format,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Solve the puzzle of a day
+    Run {
+        /// Day to solve [default: today, during the event of the year]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only solve one part of the puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input file, '-' reads from stdin [default: the input named by --variant, downloaded when missing]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+
+    /// Solve the puzzles of every day and print a summary table
+    All {
+        /// Number of days solved in parallel, 0 uses every CPU. Timings are less accurate with several jobs
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+
+        /// Solve both parts of a day concurrently
+        #[arg(long)]
+        parallel_parts: bool,
+    },
+
+    /// Benchmark the parser and both parts of a day, or of every day
+    Bench {
+        /// Day to benchmark [default: every day]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Number of measured runs
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+
+        /// Number of runs before measuring
+        #[arg(short, long, default_value_t = 1)]
+        warmup: u64,
+
+        /// Name under which the results are saved in target/bench/
+        #[arg(long, default_value = "latest")]
+        save_baseline: String,
+
+        /// Name of saved results to compare with
+        #[arg(short, long)]
+        baseline: Option<String>,
+    },
+
+    /// Submit the answer of a part for the personal input, refusing the answers known to be wrong
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit [default: the answer of the solver]
+        answer: Option<String>,
+    },
+
+    /// Create the solver module of a new day, from the root of the crate
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Title of the puzzle
+        title: Option<String>,
+    },
+
+    /// List the days with a solver
+    List,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    /// One JSON record per day and part, other messages go to stderr
+    Json,
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value.parse().map_err(|_| format!("'{}' is not a number of seconds", value))?;
+    Duration::try_from_secs_f64(seconds).map_err(|error| error.to_string())
+}
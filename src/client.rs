@@ -0,0 +1,155 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::time::Duration;
+use regex::Regex;
+use crate::error::Error;
+
+/// Website of the puzzles, any server with the same endpoints can be used instead.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// File of the working directory holding the session token, when it's not in `AOC_SESSION`.
+pub const SESSION_FILE: &str = ".aoc-session";
+
+/// Client of the puzzle website, authenticated with the session cookie of an account.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION"))).build(),
+        }
+    }
+
+    /// Client with the session token of the `AOC_SESSION` environment variable, or of the session file.
+    pub fn from_env(base_url: &str) -> Result<Client, Error> {
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => match fs::read_to_string(SESSION_FILE) {
+                Ok(session) => session,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    return Err(Error::Fetch(format!("no session token, set AOC_SESSION or write it to {}", SESSION_FILE)));
+                },
+                Err(error) => return Err(error.into()),
+            },
+        };
+        Ok(Client::new(base_url, &session))
+    }
+
+    /// Personal puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Submit the answer of a part, the website tells whether it's right.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self.agent.post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| fetch_error(&url, error))?;
+        Ok(Outcome::parse(&response.into_string()?))
+    }
+
+    fn get(&self, path: &str) -> Result<String, Error> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| fetch_error(&url, error))?;
+        Ok(response.into_string()?)
+    }
+}
+
+fn fetch_error(url: &str, error: ureq::Error) -> Error {
+    match error {
+        // The website answers 400 to an invalid session and 404 to a puzzle that is not released yet
+        ureq::Error::Status(400, _) => Error::Fetch(format!("{}: the session token was refused", url)),
+        ureq::Error::Status(404, _) => Error::Fetch(format!("{}: not found, the puzzle may not be released yet", url)),
+        ureq::Error::Status(status, response) => Error::Fetch(format!("{}: {} {}", url, status, response.status_text())),
+        ureq::Error::Transport(transport) => Error::Fetch(transport.to_string()),
+    }
+}
+
+/// Response of the website to a submitted answer.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong answer, without telling whether it's too high or too low.
+    Wrong,
+    /// An answer was submitted too recently, the answer was not checked.
+    Wait(Option<Duration>),
+    /// The part is already solved, or the first part is not solved yet.
+    WrongLevel,
+    /// Message that is none of the known ones.
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Read the message of the page returned by the website, which is in its `<article>`.
+    pub fn parse(page: &str) -> Outcome {
+        let article = page.split_once("<article>")
+            .and_then(|(_, article)| article.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+        let mut message = String::new();
+        let mut in_tag = false;
+        for c in article.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                _ if !in_tag => message.push(c),
+                _ => (),
+            }
+        }
+        let message = message.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Outcome::TooHigh
+            } else if message.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            let regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            Outcome::Wait(regex.captures(&message).map(|capture| {
+                let minutes: u64 = capture.get(1).map_or(0, |minutes| minutes.as_str().parse().unwrap_or(0));
+                let seconds: u64 = capture[2].parse().unwrap_or(0);
+                Duration::from_secs(minutes * 60 + seconds)
+            }))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown(message)
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer"),
+            Outcome::TooHigh => write!(f, "Wrong answer, too high"),
+            Outcome::TooLow => write!(f, "Wrong answer, too low"),
+            Outcome::Wrong => write!(f, "Wrong answer"),
+            Outcome::Wait(Some(wait)) => write!(f, "An answer was submitted too recently, wait {:?}", wait),
+            Outcome::Wait(None) => write!(f, "An answer was submitted too recently"),
+            Outcome::WrongLevel => write!(f, "The part is already solved, or not unlocked yet"),
+            Outcome::Unknown(message) => write!(f, "Unexpected response: {}", message),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+use crate::error::Error;
+
+thread_local! {
+    static DEADLINE: Cell<Option<(Instant, Duration)>> = const { Cell::new(None) };
+}
+
+/// Give a time budget to what runs next on the current thread, or remove it.
+pub fn start(budget: Option<Duration>) {
+    DEADLINE.with(|deadline| deadline.set(budget.map(|budget| (Instant::now() + budget, budget))));
+}
+
+/// Fail once the time budget of the current thread is spent. The solvers with loops that can
+/// run for a long time call it regularly, so that they stop when the runner gives up on them.
+pub fn check() -> Result<(), Error> {
+    match DEADLINE.with(Cell::get) {
+        Some((deadline, budget)) if Instant::now() >= deadline => Err(Error::Timeout(budget)),
+        _ => Ok(()),
+    }
+}
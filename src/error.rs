@@ -0,0 +1,92 @@
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    InvalidPuzzle(String),
+    UnknownYear(u16),
+    UnknownDay(u8),
+    UnknownPart(u8),
+    InvalidAnswers(String),
+    /// The guesses file at the path cannot be read or written.
+    InvalidGuesses {
+        path: PathBuf,
+        message: String,
+    },
+    /// The part was not solved within its time budget.
+    Timeout(Duration),
+    Fetch(String),
+    /// The solver panicked, with the message of the panic.
+    Panic(String),
+}
+
+impl Error {
+    /// Parse error at a column of the line being parsed. The line number is filled by `at_line`.
+    pub fn parse(column: usize, message: impl Into<String>) -> Error {
+        Error::Parse {
+            line: 0,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_puzzle(message: impl Into<String>) -> Error {
+        Error::InvalidPuzzle(message.into())
+    }
+
+    pub fn at_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse { line: 0, column, message } => Error::Parse { line, column, message },
+            _ => self,
+        }
+    }
+
+    /// Shift the line of a parse error in a part of the input that starts after `count` lines.
+    pub fn after_lines(self, count: usize) -> Error {
+        match self {
+            Error::Parse { line, column, message } if line > 0 => Error::Parse { line: line + count, column, message },
+            _ => self,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            Error::Parse { line, column, message } => write!(f, "parse error at line {}, column {}: {}", line, column, message),
+            Error::InvalidPuzzle(message) => write!(f, "invalid puzzle: {}", message),
+            Error::UnknownYear(year) => write!(f, "no solver found for year {}", year),
+            Error::UnknownDay(day) => write!(f, "no solver found for day {}", day),
+            Error::UnknownPart(part) => write!(f, "unknown part {}, expected 1 or 2", part),
+            Error::InvalidAnswers(message) => write!(f, "invalid answers file: {}", message),
+            Error::InvalidGuesses { path, message } => write!(f, "invalid guesses file {}: {}", path.display(), message),
+            Error::Timeout(budget) => write!(f, "not solved within {:?}", budget),
+            Error::Fetch(message) => write!(f, "cannot download: {}", message),
+            Error::Panic(message) => write!(f, "solver panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::client::Outcome;
+use crate::error::Error;
+
+/// Submitted answers of a part that the website found wrong.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Serialize, Deserialize)]
+pub struct PartGuesses {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    /// Highest answer known to be too low.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+    /// Lowest answer known to be too high.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+}
+
+/// Wrong answers submitted for the personal inputs of a year, stored as a TOML file so that they
+/// are not submitted again:
+///
+/// ```toml
+/// [day05.part1]
+/// wrong = ["6143", "6000"]
+/// too_low = 6000
+/// too_high = 6143
+/// ```
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Guesses {
+    days: BTreeMap<String, BTreeMap<String, PartGuesses>>,
+}
+
+impl Guesses {
+    /// Guesses of a year, none when the file is missing.
+    pub fn load(path: impl AsRef<Path>) -> Result<Guesses, Error> {
+        let path = path.as_ref();
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Guesses::default()),
+            Err(error) => return Err(invalid_guesses(path, error)),
+        };
+        toml::from_str(&content).map_err(|error| invalid_guesses(path, error.to_string().trim_end()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let content = toml::to_string(self).map_err(|error| invalid_guesses(path, error))?;
+        fs::write(path, content).map_err(|error| invalid_guesses(path, error))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&PartGuesses> {
+        self.days.get(&format!("day{:02}", day))?.get(&format!("part{}", part))
+    }
+
+    /// Why an answer is known to be wrong, without submitting it.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let guesses = self.get(day, part)?;
+        let answer = answer.trim();
+        if guesses.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(format!("{} was already submitted and is wrong", answer));
+        }
+
+        let value: i64 = answer.parse().ok()?;
+        match (guesses.too_low, guesses.too_high) {
+            (Some(too_low), _) if value <= too_low => Some(format!("{} is too low, {} already is", answer, too_low)),
+            (_, Some(too_high)) if value >= too_high => Some(format!("{} is too high, {} already is", answer, too_high)),
+            _ => None,
+        }
+    }
+
+    /// Remember an answer that the website found wrong, and the bounds it gave.
+    /// Returns false when the outcome doesn't tell that the answer is wrong.
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, outcome: &Outcome) -> bool {
+        if !matches!(outcome, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong) {
+            return false;
+        }
+
+        let guesses = self.days.entry(format!("day{:02}", day)).or_default().entry(format!("part{}", part)).or_default();
+        let answer = answer.trim();
+        if !guesses.wrong.iter().any(|wrong| wrong == answer) {
+            guesses.wrong.push(answer.to_string());
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            match outcome {
+                Outcome::TooLow => guesses.too_low = Some(guesses.too_low.map_or(value, |too_low| too_low.max(value))),
+                Outcome::TooHigh => guesses.too_high = Some(guesses.too_high.map_or(value, |too_high| too_high.min(value))),
+                _ => (),
+            }
+        }
+        true
+    }
+}
+
+fn invalid_guesses(path: &Path, message: impl ToString) -> Error {
+    Error::InvalidGuesses {
+        path: path.to_path_buf(),
+        message: message.to_string(),
+    }
+}
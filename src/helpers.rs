@@ -1,19 +1,46 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::collections::HashMap;
+use std::io::BufRead;
 use std::ops::{Deref, DerefMut};
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use regex::{Captures, Regex};
+use crate::error::Error;
 
-pub fn parse_file_to_list<T>(file: io::BufReader<File>, parse_func: impl Fn(&str) -> T) -> Vec<T> {
+pub mod search;
+
+pub fn parse_file_to_list<T>(file: impl BufRead, parse_func: impl Fn(&str) -> Result<T, Error>) -> Result<Vec<T>, Error> {
     let mut inputs: Vec<T> = Vec::new();
-    for line in file.lines() {
-        if let Ok(ip) = line {
-            inputs.push(parse_func(&ip));
-        }
+    for (index, line) in file.lines().enumerate() {
+        let ip = line?;
+        inputs.push(parse_func(&ip).map_err(|error| error.at_line(index + 1))?);
     }
 
-    inputs
+    Ok(inputs)
+}
+
+/// Parse a value found at the given column (1-based) of the current line.
+pub fn parse_value<T>(value: &str, column: usize) -> Result<T, Error> 
+    where T: FromStr,
+          T::Err: Display
+{
+    value.parse::<T>().map_err(|error| Error::parse(column, format!("invalid value '{}': {}", value, error)))
+}
+
+pub fn parse_regex<'t>(regex: &Regex, line: &'t str) -> Result<Captures<'t>, Error> {
+    regex.captures(line).ok_or_else(|| Error::parse(1, format!("'{}' does not match the expected format", line)))
+}
+
+pub fn parse_capture<T>(capture: &Captures, index: usize) -> Result<T, Error> 
+    where T: FromStr,
+          T::Err: Display
+{
+    match capture.get(index) {
+        Some(group) => parse_value(group.as_str(), group.start() + 1),
+        None => Err(Error::parse(capture.get(0).map_or(1, |m| m.end() + 1), format!("missing value #{}", index))),
+    }
 }
 
+#[derive(Debug)]
 #[derive(Default)]
 #[derive(Copy, Clone)]
 #[derive(Eq)]
@@ -67,7 +94,7 @@ impl std::ops::Add<Position> for Position {
 }
 
 impl Display for Position {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "({},{})", self.x, self.y)
     }
 }
@@ -97,6 +124,23 @@ impl Iterator for IntoNeighbourIterator {
     }
 }
 
+/// What a map holds outside of its cells.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum Boundary<T> {
+    /// Nothing, the positions outside of the map are ignored.
+    #[default]
+    Bounded,
+    /// The cells of the opposite side, the map repeats in every direction.
+    Wrap,
+    /// The same value everywhere outside of the map.
+    Constant(T),
+    /// Like `Constant`, but changing a cell outside of the map grows it to include the cell.
+    Grow(T),
+}
+
 /// Map
 
 #[derive(Default)]
@@ -106,6 +150,9 @@ pub struct Map<T>
 {
     pub width: usize,
     pub height: usize,
+    /// Position of the top left cell, only moved by growing maps.
+    origin: Position,
+    boundary: Boundary<T>,
     map: Box<[T]>,
 }
 
@@ -113,27 +160,85 @@ impl<T> Map<T>
     where T: Clone
 {
     pub fn get(&self, pos: Position) -> Option<&T> {
-        if pos.x < 0 || pos.x as usize >= self.width || pos.y < 0 || pos.y as usize >= self.height {
-            return None
-        } 
-
-        Some(&self.map[pos.y as usize * self.width + pos.x as usize])
+        match (self.index(pos), &self.boundary) {
+            (Some(index), _) => Some(&self.map[index]),
+            (None, Boundary::Constant(value) | Boundary::Grow(value)) => Some(value),
+            (None, _) => None,
+        }
     }
 
+    /// Cell at a position, growing maps first grow to include it.
     pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
-        if pos.x < 0 || pos.x as usize >= self.width || pos.y < 0 || pos.y as usize >= self.height {
-            return None
-        } 
+        if let Boundary::Grow(_) = self.boundary {
+            self.grow(pos);
+        }
 
-        Some(&mut self.map[pos.y as usize * self.width + pos.x as usize])
+        self.index(pos).map(|index| &mut self.map[index])
     }
 
     pub fn set(&mut self, pos: Position, value: T) {
+        if let Some(cell) = self.get_mut(pos) {
+            *cell = value;
+        }
+    }
+
+    pub fn boundary(&self) -> &Boundary<T> {
+        &self.boundary
+    }
+
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    /// Position of the cell read at a position, which differs from it for wrapping maps.
+    pub fn wrap(&self, pos: Position) -> Position {
+        match self.boundary {
+            Boundary::Wrap if self.width > 0 && self.height > 0 => Position::new(
+                self.origin.x + (pos.x - self.origin.x).rem_euclid(self.width as i32),
+                self.origin.y + (pos.y - self.origin.y).rem_euclid(self.height as i32)),
+            _ => pos,
+        }
+    }
+
+    /// Neighbours of a position that have a value, with their wrapped position.
+    pub fn neighbours(&self, pos: Position, diags: bool) -> impl Iterator<Item = (Position, &T)> + '_ {
+        pos.neighbours(diags).filter_map(|neighbour| self.get(neighbour).map(|cell| (self.wrap(neighbour), cell)))
+    }
+
+    fn index(&self, pos: Position) -> Option<usize> {
+        let pos = self.wrap(pos) - self.origin;
         if pos.x < 0 || pos.x as usize >= self.width || pos.y < 0 || pos.y as usize >= self.height {
+            return None
+        }
+
+        Some(pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// Grow the map to include a position, the new cells have the value of the boundary.
+    fn grow(&mut self, pos: Position) {
+        let Boundary::Grow(value) = &self.boundary else { return };
+        if self.index(pos).is_some() {
             return;
-        } 
+        }
 
-        self.map[pos.y as usize * self.width + pos.x as usize] = value;
+        let (left, top) = if self.width == 0 || self.height == 0 { (pos.x, pos.y) } else { (self.origin.x.min(pos.x), self.origin.y.min(pos.y)) };
+        let right = (self.origin.x + self.width as i32).max(pos.x + 1);
+        let bottom = (self.origin.y + self.height as i32).max(pos.y + 1);
+        let mut grown = Map::from_fn((right - left) as usize, (bottom - top) as usize, |_| value.clone());
+        for (cell_pos, cell) in self.cells() {
+            let cell_pos = cell_pos - Position::new(left, top);
+            grown.map[cell_pos.y as usize * grown.width + cell_pos.x as usize] = cell.clone();
+        }
+
+        self.width = grown.width;
+        self.height = grown.height;
+        self.origin = Position::new(left, top);
+        self.map = grown.map;
     }
 }
 
@@ -144,6 +249,8 @@ impl<T> Map<T>
         Map {
             width,
             height,
+            origin: Position::default(),
+            boundary: Boundary::Bounded,
             map: vec![Default::default(); width * height].into_boxed_slice(),
         }
     }
@@ -152,20 +259,355 @@ impl<T> Map<T>
         Map {
             width,
             height,
+            origin: Position::default(),
+            boundary: Boundary::Bounded,
             map: vec![default; width * height].into_boxed_slice(),
         }
     }
 }
 
+impl<T> Map<T>
+    where T: Clone
+{
+    /// Read a grid with a row per line, converting each character with `parse_cell`.
+    /// Every row must have the same width, the blank lines around the grid are ignored.
+    pub fn parse_grid(input: impl BufRead, parse_cell: impl Fn(char) -> Result<T, String>) -> Result<Self, Error> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        let mut ended = false;
+        for (index, line) in input.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                ended = height > 0;
+                continue;
+            } else if ended {
+                return Err(Error::parse(1, "expected a single grid").at_line(index + 1));
+            }
+
+            let start = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                cells.push(parse_cell(c).map_err(|message| Error::parse(column + 1, message).at_line(index + 1))?);
+            }
+
+            match width {
+                Some(width) if width != cells.len() - start => {
+                    return Err(Error::parse(1, format!("expected {} cells", width)).at_line(index + 1));
+                },
+                _ => width = Some(cells.len() - start),
+            }
+            height += 1;
+        }
+
+        Ok(Map {
+            width: width.unwrap_or(0),
+            height,
+            origin: Position::default(),
+            boundary: Boundary::Bounded,
+            map: cells.into_boxed_slice(),
+        })
+    }
+}
+
+impl<T> Map<T>
+    where T: Clone
+{
+    /// Map of the given size with the cell of each position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        Map {
+            width,
+            height,
+            origin: Position::default(),
+            boundary: Boundary::Bounded,
+            map: (0..width * height).map(|index| cell(position_of(index, width))).collect(),
+        }
+    }
+
+    fn cell(&self, x: usize, y: usize) -> &T {
+        &self.map[y * self.width + x]
+    }
+
+    /// Positions of the cells, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let (width, origin) = (self.width, self.origin);
+        (0..self.width * self.height).map(move |index| origin + position_of(index, width))
+    }
+
+    /// Cells with their position, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(self.map.iter())
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> + '_ {
+        self.positions().zip(self.map.iter_mut())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.map.chunks(self.width.max(1))
+    }
+
+    /// Cells of each column, from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| (0..self.height).map(move |y| self.cell(x, y)))
+    }
+
+    /// Map with the rows and the columns swapped.
+    /// The transformed maps keep the boundary, with their top left cell at (0,0).
+    pub fn transpose(&self) -> Self {
+        Map::from_fn(self.height, self.width, |pos| self.cell(pos.y as usize, pos.x as usize).clone())
+            .with_boundary(self.boundary.clone())
+    }
+
+    /// Map mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Map::from_fn(self.width, self.height, |pos| self.cell(self.width - 1 - pos.x as usize, pos.y as usize).clone())
+            .with_boundary(self.boundary.clone())
+    }
+
+    /// Map mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Map::from_fn(self.width, self.height, |pos| self.cell(pos.x as usize, self.height - 1 - pos.y as usize).clone())
+            .with_boundary(self.boundary.clone())
+    }
+
+    /// Map rotated a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self {
+        Map::from_fn(self.height, self.width, |pos| self.cell(pos.y as usize, self.height - 1 - pos.x as usize).clone())
+            .with_boundary(self.boundary.clone())
+    }
+
+    /// Map rotated a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        Map::from_fn(self.height, self.width, |pos| self.cell(self.width - 1 - pos.y as usize, pos.x as usize).clone())
+            .with_boundary(self.boundary.clone())
+    }
+
+    /// Rectangle of the map with its top left corner at `origin`, limited to the map.
+    pub fn view(&self, origin: Position, width: usize, height: usize) -> MapView<'_, T> {
+        let origin = origin - self.origin;
+        let x = (origin.x.max(0) as usize).min(self.width);
+        let y = (origin.y.max(0) as usize).min(self.height);
+        // The part of the rectangle before the map is cut
+        let width = width.saturating_sub((-origin.x).max(0) as usize);
+        let height = height.saturating_sub((-origin.y).max(0) as usize);
+        MapView {
+            map: self,
+            x,
+            y,
+            width: width.min(self.width - x),
+            height: height.min(self.height - y),
+        }
+    }
+
+    /// Copy of a rectangle of the map, limited to the map.
+    pub fn crop(&self, origin: Position, width: usize, height: usize) -> Self {
+        self.view(origin, width, height).to_map()
+    }
+}
+
+fn position_of(index: usize, width: usize) -> Position {
+    Position::new((index % width) as i32, (index / width) as i32)
+}
+
+/// Rectangle of a map, with positions relative to its top left corner.
+pub struct MapView<'a, T>
+    where T: Clone
+{
+    map: &'a Map<T>,
+    x: usize,
+    y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> MapView<'a, T>
+    where T: Clone
+{
+    pub fn get(&self, pos: Position) -> Option<&'a T> {
+        if pos.x < 0 || pos.x as usize >= self.width || pos.y < 0 || pos.y as usize >= self.height {
+            return None
+        }
+
+        Some(self.map.cell(self.x + pos.x as usize, self.y + pos.y as usize))
+    }
+
+    /// Cells with their position in the view, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &'a T)> + '_ {
+        (0..self.width * self.height).map(|index| {
+            let pos = position_of(index, self.width);
+            (pos, self.map.cell(self.x + pos.x as usize, self.y + pos.y as usize))
+        })
+    }
+
+    pub fn to_map(&self) -> Map<T> {
+        Map::from_fn(self.width, self.height, |pos| self.map.cell(self.x + pos.x as usize, self.y + pos.y as usize).clone())
+    }
+}
+
+/// Map storing only the cells that were set, for grids of unknown or very large size.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone)]
+pub struct SparseMap<T> {
+    cells: HashMap<Position, T>,
+    /// Top left and bottom right corners of the cells.
+    bounds: Option<(Position, Position)>,
+}
+
+impl<T> SparseMap<T> {
+    pub fn new() -> Self {
+        SparseMap {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn set(&mut self, pos: Position, value: T) {
+        self.include(pos);
+        self.cells.insert(pos, value);
+    }
+
+    /// Cell at a position, which is first set to the default value when missing.
+    pub fn get_or_insert_default(&mut self, pos: Position) -> &mut T
+        where T: Default
+    {
+        self.include(pos);
+        self.cells.entry(pos).or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Cells with their position, in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.values()
+    }
+
+    /// Top left and bottom right corners of the rectangle including every cell, None without cells.
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        self.bounds
+    }
+
+    /// Dense map of the rectangle including every cell, with `empty` for the missing cells.
+    /// It keeps the positions of the cells, its origin being the top left corner.
+    pub fn to_map(&self, empty: T) -> Map<T>
+        where T: Clone
+    {
+        let Some((top_left, bottom_right)) = self.bounds else { return Map::from_fn(0, 0, |_| empty.clone()) };
+        let size = bottom_right - top_left;
+        let mut map = Map::from_fn(size.x as usize + 1, size.y as usize + 1, |pos| self.get(top_left + pos).unwrap_or(&empty).clone());
+        map.origin = top_left;
+        map
+    }
+
+    fn include(&mut self, pos: Position) {
+        self.bounds = Some(match self.bounds {
+            Some((top_left, bottom_right)) => (
+                Position::new(top_left.x.min(pos.x), top_left.y.min(pos.y)),
+                Position::new(bottom_right.x.max(pos.x), bottom_right.y.max(pos.y))),
+            None => (pos, pos),
+        });
+    }
+}
+
+impl<T> FromIterator<(Position, T)> for SparseMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Position, T)>>(cells: I) -> Self {
+        let mut map = SparseMap::new();
+        for (pos, cell) in cells {
+            map.set(pos, cell);
+        }
+
+        map
+    }
+}
+
+impl<T> From<&Map<T>> for SparseMap<T>
+    where T: Clone
+{
+    /// Every cell of the map, filter its `cells()` to only keep some of them.
+    fn from(map: &Map<T>) -> Self {
+        map.cells().map(|(pos, cell)| (pos, cell.clone())).collect()
+    }
+}
+
+impl<T> Display for SparseMap<T>
+    where T: Display
+{
+    /// Same rendering as `Map`, with `.` for the missing cells.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some((top_left, bottom_right)) = self.bounds else { return Ok(()) };
+        for y in top_left.y..=bottom_right.y {
+            for x in top_left.x..=bottom_right.x {
+                match self.get(Position::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Cell of a grid that is read from a single character.
+pub trait FromChar: Sized {
+    fn from_char(c: char) -> Result<Self, String>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Result<char, String> {
+        Ok(c)
+    }
+}
+
+macro_rules! digit_from_char {
+    ($($type:ty),*) => {
+        $(impl FromChar for $type {
+            fn from_char(c: char) -> Result<$type, String> {
+                c.to_digit(10).map(|digit| digit as $type).ok_or_else(|| format!("expected a digit, found '{}'", c))
+            }
+        })*
+    };
+}
+
+digit_from_char!(u8, u32, i32, usize);
+
+impl<T> FromStr for Map<T>
+    where T: Clone + FromChar
+{
+    type Err = Error;
+
+    fn from_str(grid: &str) -> Result<Self, Error> {
+        Map::parse_grid(grid.as_bytes(), T::from_char)
+    }
+}
+
 impl<T> Display for Map<T>
     where T: Clone + Display
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for y in 0..self.height {
             for x in 0..self.width {
-                if let Err(error) = write!(f, "{}", self.get(Position::new(x as i32, y as i32)).unwrap()) {
-                    return Err(error);
-                }
+                write!(f, "{}", self.cell(x, y))?;
             }
 
             if let Err(error) = writeln!(f, "") {
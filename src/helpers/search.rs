@@ -0,0 +1,143 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cheapest way found from the start to a goal, with the states it goes through including both.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// States met by a search, numbered to keep their cost and the state they are reached from.
+struct Visited<S, C> {
+    indexes: HashMap<S, usize>,
+    states: Vec<(S, C, usize)>,
+}
+
+impl<S, C> Visited<S, C>
+    where S: Clone + Eq + Hash,
+          C: Copy
+{
+    fn new(start: S, cost: C) -> Self {
+        Visited {
+            indexes: HashMap::from([(start.clone(), 0)]),
+            states: vec![(start, cost, 0)],
+        }
+    }
+
+    /// Record that a state is reached from the state `from` with a cost, and return its index.
+    /// States already reached are only updated when `better` accepts their current cost.
+    fn reach(&mut self, state: S, cost: C, from: usize, better: impl FnOnce(C) -> bool) -> Option<usize> {
+        match self.indexes.get(&state) {
+            Some(&index) if better(self.states[index].1) => {
+                self.states[index] = (state, cost, from);
+                Some(index)
+            },
+            Some(_) => None,
+            None => {
+                self.indexes.insert(state.clone(), self.states.len());
+                self.states.push((state, cost, from));
+                Some(self.states.len() - 1)
+            },
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Path<S, C> {
+        let cost = self.states[index].1;
+        let mut states = vec![self.states[index].0.clone()];
+        while index != 0 {
+            index = self.states[index].2;
+            states.push(self.states[index].0.clone());
+        }
+
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+/// Path with the fewest steps from the start to a goal, the cost being the number of steps.
+pub fn bfs<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, usize>>
+    where S: Clone + Eq + Hash,
+          I: IntoIterator<Item = S>
+{
+    let mut visited = Visited::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, index)) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(visited.path(index));
+        }
+
+        let steps = visited.states[index].1 + 1;
+        for neighbour in neighbours(&state) {
+            if let Some(neighbour_index) = visited.reach(neighbour.clone(), steps, index, |_| false) {
+                queue.push_back((neighbour, neighbour_index));
+            }
+        }
+    }
+
+    None
+}
+
+/// Cheapest path from the start to a goal, `neighbours` giving the cost of the step to each of them.
+pub fn dijkstra<S, C, I>(start: S, neighbours: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output = C>,
+          I: IntoIterator<Item = (S, C)>
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Cheapest path from the start to a goal, exploring first the states whose cost plus `heuristic` is lowest.
+/// The heuristic must never overestimate the cost left to reach a goal, and the costs start from `C::default()`.
+pub fn astar<S, C, I>(start: S, mut neighbours: impl FnMut(&S) -> I, mut heuristic: impl FnMut(&S) -> C, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output = C>,
+          I: IntoIterator<Item = (S, C)>
+{
+    let mut open_set = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start, C::default());
+    while let Some(Reverse((_, cost, index))) = open_set.pop() {
+        // A cheaper way to this state was found after this one was queued
+        if cost > visited.states[index].1 {
+            continue;
+        }
+
+        let state = visited.states[index].0.clone();
+        if is_goal(&state) {
+            return Some(visited.path(index));
+        }
+
+        for (neighbour, step) in neighbours(&state) {
+            let neighbour_cost = cost + step;
+            let estimate = neighbour_cost + heuristic(&neighbour);
+            if let Some(neighbour_index) = visited.reach(neighbour, neighbour_cost, index, |known| neighbour_cost < known) {
+                open_set.push(Reverse((estimate, neighbour_cost, neighbour_index)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every state reachable from the start, including it, in the order they are reached.
+pub fn flood_fill<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> Vec<S>
+    where S: Clone + Eq + Hash,
+          I: IntoIterator<Item = S>
+{
+    let mut visited = Visited::new(start, ());
+    let mut next = 0;
+    while next < visited.states.len() {
+        let state = visited.states[next].0.clone();
+        for neighbour in neighbours(&state) {
+            visited.reach(neighbour, (), next, |_| false);
+        }
+
+        next += 1;
+    }
+
+    visited.states.into_iter().map(|(state, _, _)| state).collect()
+}
@@ -0,0 +1,70 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::client::Client;
+use crate::error::Error;
+
+/// Directory of the puzzle data, relative to the working directory.
+pub const DATA_DIR: &str = "data";
+
+/// Name of the personal puzzle input of a day, the other inputs are variants such as examples.
+pub const DEFAULT_INPUT: &str = "input";
+
+/// Inputs of a day are stored as `data/<year>/dayNN/<name>.txt`.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    Path::new(DATA_DIR).join(year.to_string()).join(format!("day{:02}", day))
+}
+
+pub fn input_path(year: u16, day: u8, name: &str) -> PathBuf {
+    day_dir(year, day).join(format!("{}.txt", name))
+}
+
+/// Expected answers of every input of a year.
+pub fn answers_path(year: u16) -> PathBuf {
+    Path::new(DATA_DIR).join(year.to_string()).join("answers.toml")
+}
+
+/// Wrong answers submitted for the personal inputs of a year.
+pub fn guesses_path(year: u16) -> PathBuf {
+    Path::new(DATA_DIR).join(year.to_string()).join("guesses.toml")
+}
+
+/// Names of the inputs of a day, the default input first and then by name.
+pub fn input_names(year: u16, day: u8) -> Result<Vec<String>, Error> {
+    let entries = match fs::read_dir(day_dir(year, day)) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names.sort_by_key(|name| (name != DEFAULT_INPUT, name.clone()));
+    Ok(names)
+}
+
+/// Download the personal input of a day to `path`, unless it's already there: inputs never
+/// change, so they are only fetched once. Returns whether the input was downloaded.
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<bool, Error> {
+    if path.exists() {
+        return Ok(false);
+    }
+
+    let input = client.input(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // An interrupted download must not be taken for the input
+    let partial = path.with_extension("part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, path)?;
+    Ok(true)
+}
@@ -0,0 +1,153 @@
+use std::io::BufRead;
+use error::Error;
+
+pub use answer::Answer;
+
+mod answer;
+pub mod answers;
+pub mod client;
+pub mod deadline;
+pub mod error;
+pub mod guesses;
+pub mod helpers;
+pub mod inputs;
+pub mod ocr;
+pub mod solvers;
+mod tests;
+
+/// Parse the puzzle input of the given year and day, and solve one of its parts (1 or 2).
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<Answer, Error> {
+    let mut solver = get_solvers(year, day)?;
+    solver.parse(&mut input.as_bytes())?;
+    match part {
+        1 => solver.part1(),
+        2 => solver.part2(),
+        _ => Err(Error::UnknownPart(part)),
+    }
+}
+
+/// Puzzle of a day, as registered by its module with the `puzzle!` macro.
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub solver: fn() -> Box<dyn Solver>,
+}
+
+impl Puzzle {
+    /// Type of the answers of both parts, as returned by the solvers.
+    pub fn answer_types(&self) -> [String; 2] {
+        (self.solver)().answer_types()
+    }
+}
+
+/// Registered years, in order.
+pub fn years() -> impl Iterator<Item = u16> {
+    solvers::YEARS.iter().map(|&(year, _)| year)
+}
+
+/// Most recent registered year.
+pub fn latest_year() -> u16 {
+    years().max().expect("At least one year is registered")
+}
+
+/// Puzzles of every registered day of a year, in order.
+pub fn puzzles(year: u16) -> Result<&'static [Puzzle], Error> {
+    solvers::YEARS.iter()
+        .find(|&&(registered, _)| registered == year)
+        .map(|&(_, puzzles)| puzzles)
+        .ok_or(Error::UnknownYear(year))
+}
+
+pub fn get_puzzle(year: u16, day: u8) -> Result<&'static Puzzle, Error> {
+    puzzles(year)?.iter().find(|puzzle| puzzle.day == day).ok_or(Error::UnknownDay(day))
+}
+
+/// Solver registered for the given year and day.
+pub fn get_solvers(year: u16, day: u8) -> Result<Box<dyn Solver>, Error> {
+    get_puzzle(year, day).map(|puzzle| (puzzle.solver)())
+}
+
+/// Type-erased day solver: parse the input once, then solve each part on the parsed data.
+/// Solvers can be shared between threads, to solve both parts concurrently.
+pub trait Solver: Send + Sync {
+    fn parse(&mut self, input: &mut dyn BufRead) -> Result<(), Error>;
+
+    fn part1(&self) -> Result<Answer, Error>;
+    
+    fn part2(&self) -> Result<Answer, Error>;
+
+    fn answer_types(&self) -> [String; 2];
+}
+
+pub struct DaySolver<T, R1, R2> {
+    parser: fn(&mut dyn BufRead) -> Result<T, Error>,
+    solver1: fn(&T) -> Result<R1, Error>,
+    solver2: fn(&T) -> Result<R2, Error>,
+    data: T,
+}
+
+impl<T, R1, R2> DaySolver<T, R1, R2> 
+    where T: Default,
+          R1: Into<Answer>,
+          R2: Into<Answer>,
+{
+    pub fn from(parser: fn(&mut dyn BufRead) -> Result<T, Error>, solver1: fn(&T) -> Result<R1, Error>, solver2: fn(&T) -> Result<R2, Error>) -> DaySolver<T, R1, R2> {
+        DaySolver {
+            parser,
+            solver1,
+            solver2,
+            data: Default::default(),
+        }
+    }
+
+    pub fn solve_part1(&self) -> Result<R1, Error> {
+        (self.solver1)(&self.data)
+    }
+
+    pub fn solve_part2(&self) -> Result<R2, Error> {
+        (self.solver2)(&self.data)
+    }
+}
+
+impl<T, R1, R2> Solver for DaySolver<T, R1, R2> 
+    where T: Send + Sync,
+          R1: Into<Answer>,
+          R2: Into<Answer>
+{
+    fn parse(&mut self, input: &mut dyn BufRead) -> Result<(), Error> {
+        self.data = (self.parser)(input)?;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok((self.solver1)(&self.data)?.into())
+    }
+    
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok((self.solver2)(&self.data)?.into())
+    }
+
+    fn answer_types(&self) -> [String; 2] {
+        [short_type_name::<R1>(), short_type_name::<R2>()]
+    }
+}
+
+/// Type name without the module paths of the type and its parameters,
+/// `Vec<String>` rather than `alloc::vec::Vec<alloc::string::String>`.
+fn short_type_name<T>() -> String {
+    let mut short_name = String::new();
+    let mut path = String::new();
+    for c in std::any::type_name::<T>().chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short_name.push_str(path.rsplit("::").next().unwrap_or_default());
+            short_name.push(c);
+            path.clear();
+        }
+    }
+
+    short_name.push_str(path.rsplit("::").next().unwrap_or_default());
+    short_name
+}
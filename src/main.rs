@@ -1,175 +1,454 @@
-use std::env;
-use std::fmt;
 use std::fs::File;
-use std::io;
-use std::path::Path;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+use std::thread;
 use std::time::{Duration, Instant};
 use chrono::Datelike;
+use clap::Parser;
+use aoc::{get_puzzle, latest_year, puzzles, Answer, Solver};
+use aoc::answers::{Answers, InputAnswers, Verdict};
+use aoc::answers;
+use aoc::client::{Client, Outcome};
+use aoc::guesses::Guesses;
+use aoc::error::Error;
+use aoc::inputs::{self, DEFAULT_INPUT};
+use cli::{Cli, Command, Format};
 
-mod helpers;
-mod solvers;
-mod tests;
+mod bench;
+mod cli;
+mod memory;
+mod runner;
+mod scaffold;
 
 fn main() {
-    let mut day: u8 = 0;
-    let mut input_path: String = String::new();
-    
-    {
-        let args: Vec<String> = env::args().collect();
-        match args.len() {
-            1 => (),
-            2 => {
-                if let Ok(number) = args[1].parse::<u8>() {
-                    day = number;
-                } else {
-                    input_path = args[1].clone();
-                }
-            },
-            3 => {
-                if let Ok(number) = args[1].parse::<u8>() {
-                    day = number;
-                } else {
-                    println!("usage: aoc2021 [day] [input-path]");
-                }
-    
-                input_path = args[2].clone();
+    let cli = Cli::parse();
+    let year = cli.year.unwrap_or_else(latest_year);
+    // A new day can start a new year
+    if !matches!(cli.command, Command::New { .. }) {
+        if let Err(error) = puzzles(year) {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    }
+
+    let success = match cli.command {
+        Command::Run { day, part, ref input } => {
+            match day.or_else(|| today(year)) {
+                Some(day) => run_day(year, day, part, input.as_deref(), &cli),
+                None => {
+                    eprintln!("error: no puzzle of {} today, give the day to solve", year);
+                    false
+                },
             }
-            _ => {
-                println!("usage: aoc2021 [day] [input-path]");
-                return;
+        },
+        Command::All { jobs, parallel_parts } => run_all(year, jobs, parallel_parts, &cli),
+        Command::Bench { day, runs, warmup, ref save_baseline, ref baseline } => {
+            bench(year, day, runs as usize, warmup as usize, save_baseline, baseline.as_deref(), &cli)
+        },
+        Command::Submit { day, part, ref answer } => submit(year, day, part, answer.as_deref(), &cli),
+        Command::New { day, ref title } => {
+            let title = title.clone().unwrap_or_else(|| format!("Day {}", day));
+            match scaffold::new_day(Path::new(""), year, day, &title) {
+                Ok(changed) => {
+                    changed.iter().for_each(|path| println!("{}", path.display()));
+                    true
+                },
+                Err(error) => {
+                    eprintln!("error: {} day {}: {}", year, day, error);
+                    false
+                },
             }
-        }
+        },
+        Command::List => {
+            list(year);
+            true
+        },
+    };
+
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// Day of the puzzle released today, only during the event of the given year.
+fn today(year: u16) -> Option<u8> {
+    let today = chrono::Local::today();
+    if today.year() == year as i32 && today.month() == 12 && today.day() <= 25 { Some(today.day() as u8) } else { None }
+}
+
+/// Expected answers of the inputs of a year, none when the answers file is missing.
+fn load_answers(year: u16) -> Answers {
+    let path = inputs::answers_path(year);
+    match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(Error::Io(error)) if error.kind() == io::ErrorKind::NotFound => Answers::default(),
+        Err(error) => {
+            eprintln!("warning: {}: {}", path.display(), error);
+            Answers::default()
+        },
     }
+}
 
-    if day == 0 {
-        day = chrono::Local::today().day() as u8;
+/// Path of the input of a day named by --variant. The default input is downloaded when it's missing.
+fn input_path(year: u16, day: u8, cli: &Cli) -> Result<PathBuf, Error> {
+    get_puzzle(year, day)?;
+    let path = inputs::input_path(year, day, &cli.variant);
+    if cli.variant == DEFAULT_INPUT && !path.exists() {
+        let client = Client::from_env(&cli.base_url)?;
+        eprintln!("Downloading the input of {} day {} to {}", year, day, path.display());
+        inputs::fetch_input(&client, year, day, &path)?;
     }
+    Ok(path)
+}
 
-    let path = Path::new("data").join(if input_path.is_empty() { format!("day{:02}.txt", day) } else { input_path });
+fn list(year: u16) {
+    let puzzles = puzzles(year).unwrap_or_default();
+    let title_width = puzzles.iter().map(|puzzle| puzzle.title.len()).max().unwrap_or(0);
+    let answer_types: Vec<String> = puzzles.iter().map(|puzzle| puzzle.answer_types().join(", ")).collect();
+    let types_width = answer_types.iter().map(String::len).max().unwrap_or(0);
+    for (puzzle, answer_types) in puzzles.iter().zip(answer_types) {
+        let input_names = match inputs::input_names(year, puzzle.day) {
+            Ok(names) if names.is_empty() => String::from("(missing input)"),
+            Ok(names) => names.join(", "),
+            Err(error) => format!("({})", error),
+        };
+        println!("Day {:2}  {:title_width$}  {:types_width$}  {}", puzzle.day, puzzle.title, answer_types, input_names);
+    }
+}
 
-    println!("### Day {} ###", day);
-    
-    if let Some(mut solver) = get_solvers(day) {
-        {
-            println!("### Parsing input ###");
-            println!("Reading: {}", path.display());
-            let start = Instant::now();
-            let file = io::BufReader::new(File::open(&path).expect("Failed to read file"));
-            solver.parse(file);
-            let duration = start.elapsed();
-            println!("Took {}", fmt_dur(duration));
+fn run_all(year: u16, jobs: usize, parallel_parts: bool, cli: &Cli) -> bool {
+    let answers = load_answers(year);
+    let mut success = true;
+    let mut days = Vec::new();
+    for puzzle in puzzles(year).unwrap_or_default() {
+        // Only the default input is expected for every day
+        if cli.variant != DEFAULT_INPUT && !inputs::input_path(year, puzzle.day, &cli.variant).exists() {
+            continue;
         }
-        
-        println!("");
 
-        {
-            println!("### Running Part 1 ###");
-            let start = Instant::now();
-            solver.part1();
-            let duration = start.elapsed();
-            println!("Took {}", fmt_dur(duration));
+        match input_path(year, puzzle.day, cli) {
+            Ok(path) => days.push((puzzle.day, path)),
+            Err(error) => {
+                eprintln!("error: day {}: {}", puzzle.day, error);
+                success = false;
+            },
         }
+    }
 
-        println!("");
+    let jobs = if jobs == 0 { thread::available_parallelism().map_or(1, |jobs| jobs.get()) } else { jobs };
+    let start = Instant::now();
+    let mut results = Vec::new();
+    for ((day, path), result) in days.iter().zip(runner::solve_days(year, &cli.variant, &days, jobs, parallel_parts, cli.timeout)) {
+        match result {
+            Ok(mut result) => {
+                result.check(answers.get(*day, &cli.variant));
+                runner::print_errors(&result, &path.display().to_string());
+                results.push(result);
+            },
+            Err(error) => {
+                eprintln!("error: day {}: {}", day, error);
+                success = false;
+            },
+        }
+    }
+    let elapsed = start.elapsed();
 
-        {
-            println!("### Running Part 2 ###");
-            let start = Instant::now();
-            solver.part2();
-            let duration = start.elapsed();
-            println!("Took {}", fmt_dur(duration));
+    if cli.format == Format::Json {
+        results.iter().for_each(runner::print_json);
+    } else if cli.quiet {
+        for part in results.iter().flat_map(|result| result.parts.iter()) {
+            if let Ok(answer) = &part.answer {
+                println!("{}", answer);
+            }
         }
     } else {
-        println!("No solver found for day {}.", day);
+        runner::print_table(&results);
+        if jobs > 1 || parallel_parts {
+            println!("Elapsed {}", fmt_dur(elapsed));
+        }
+        if results.iter().any(|result| result.parse_memory.is_some()) {
+            println!();
+            runner::print_memory_table(&results);
+        }
     }
+
+    success && results.iter().all(|result| result.success())
 }
 
-fn get_solvers(day: u8) -> Option<Box<dyn Solver>> {
-    match day {
-        1 => Some(Box::new(DaySolver::from(solvers::day01::parser, solvers::day01::part1, solvers::day01::part2))),
-        2 => Some(Box::new(DaySolver::from(solvers::day02::parser, solvers::day02::part1, solvers::day02::part2))),
-        3 => Some(Box::new(DaySolver::from(solvers::day03::parser, solvers::day03::part1, solvers::day03::part2))),
-        4 => Some(Box::new(DaySolver::from(solvers::day04::parser, solvers::day04::part1, solvers::day04::part2))),
-        5 => Some(Box::new(DaySolver::from(solvers::day05::parser, solvers::day05::part1, solvers::day05::part2))),
-        6 => Some(Box::new(DaySolver::from(solvers::day06::parser, solvers::day06::part1, solvers::day06::part2))),
-        7 => Some(Box::new(DaySolver::from(solvers::day07::parser, solvers::day07::part1, solvers::day07::part2))),
-        8 => Some(Box::new(DaySolver::from(solvers::day08::parser, solvers::day08::part1, solvers::day08::part2))),
-        9 => Some(Box::new(DaySolver::from(solvers::day09::parser, solvers::day09::part1, solvers::day09::part2))),
-        10 => Some(Box::new(DaySolver::from(solvers::day10::parser, solvers::day10::part1, solvers::day10::part2))),
-        11 => Some(Box::new(DaySolver::from(solvers::day11::parser, solvers::day11::part1, solvers::day11::part2))),
-        12 => Some(Box::new(DaySolver::from(solvers::day12::parser, solvers::day12::part1, solvers::day12::part2))),
-        13 => Some(Box::new(DaySolver::from(solvers::day13::parser, solvers::day13::part1, solvers::day13::part2))),
-        14 => Some(Box::new(DaySolver::from(solvers::day14::parser, solvers::day14::part1, solvers::day14::part2))),
-        15 => Some(Box::new(DaySolver::from(solvers::day15::parser, solvers::day15::part1, solvers::day15::part2))),
-        16 => Some(Box::new(DaySolver::from(solvers::day16::parser, solvers::day16::part1, solvers::day16::part2))),
-        17 => Some(Box::new(DaySolver::from(solvers::day17::parser, solvers::day17::part1, solvers::day17::part2))),
-        18 => Some(Box::new(DaySolver::from(solvers::day18::parser, solvers::day18::part1, solvers::day18::part2))),
-        19 => Some(Box::new(DaySolver::from(solvers::day19::parser, solvers::day19::part1, solvers::day19::part2))),
-        20 => Some(Box::new(DaySolver::from(solvers::day20::parser, solvers::day20::part1, solvers::day20::part2))),
-        21 => Some(Box::new(DaySolver::from(solvers::day21::parser, solvers::day21::part1, solvers::day21::part2))),
-        22 => Some(Box::new(DaySolver::from(solvers::day22::parser, solvers::day22::part1, solvers::day22::part2))),
-        23 => Some(Box::new(DaySolver::from(solvers::day23::parser, solvers::day23::part1, solvers::day23::part2))),
-        24 => Some(Box::new(DaySolver::from(solvers::day24::parser, solvers::day24::part1, solvers::day24::part2))),
-        25 => Some(Box::new(DaySolver::from(solvers::day25::parser, solvers::day25::part1, solvers::day25::part2))),
-        _ => None,
+fn bench(year: u16, day: Option<u8>, runs: usize, warmup: usize, save_name: &str, baseline_name: Option<&str>, cli: &Cli) -> bool {
+    let baseline = match baseline_name.map(bench::load_baseline) {
+        Some(Ok(baseline)) => baseline,
+        Some(Err(error)) => {
+            eprintln!("error: baseline {}: {}", bench::baseline_path(baseline_name.unwrap_or_default()).display(), error);
+            return false;
+        },
+        None => Vec::new(),
+    };
+
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => puzzles(year).unwrap_or_default().iter().map(|puzzle| puzzle.day).collect(),
+    };
+
+    let mut success = true;
+    let mut records = Vec::new();
+    for day in days {
+        let path = match input_path(year, day, cli) {
+            Ok(path) => path,
+            Err(error) => {
+                eprintln!("error: day {}: {}", day, error);
+                success = false;
+                continue;
+            },
+        };
+        match bench::bench_day(year, day, &cli.variant, &path, warmup, runs, cli.timeout) {
+            Ok(day_records) => records.extend(day_records),
+            Err(error) => {
+                eprintln!("error: day {}: {}: {}", day, path.display(), error);
+                success = false;
+            },
+        }
+    }
+
+    if let Err(error) = bench::save_baseline(save_name, &records) {
+        eprintln!("error: {}: {}", bench::baseline_path(save_name).display(), error);
+        success = false;
+    }
+
+    if cli.format == Format::Json {
+        for record in &records {
+            println!("{}", serde_json::to_string(record).expect("records are serializable"));
+        }
+    } else {
+        bench::print_table(&records, &baseline);
     }
+
+    success
 }
 
-trait Solver {
-    fn parse(&mut self, input_file: io::BufReader<File>);
+/// Submit an answer for the personal input of a day, solving the part when no answer is given.
+/// The correct answers are added to the answers file, the wrong ones are remembered.
+fn submit(year: u16, day: u8, part: u8, answer: Option<&str>, cli: &Cli) -> bool {
+    let answer = match answer {
+        Some(answer) => answer.trim().to_string(),
+        None => match solve_for_submit(year, day, part, cli) {
+            Ok(answer) => {
+                println!("Answer: {}", answer);
+                answer
+            },
+            Err(error) => {
+                eprintln!("error: day {} part {}: {}", day, part, error);
+                return false;
+            },
+        },
+    };
+
+    match load_answers(year).expected(day, DEFAULT_INPUT, part) {
+        Some(expected) if expected.trim() == answer => {
+            println!("Already solved, {} is the right answer", answer);
+            return true;
+        },
+        Some(expected) => {
+            eprintln!("error: not submitting {}, the right answer is {}", answer, expected.trim());
+            return false;
+        },
+        None => (),
+    }
 
-    fn part1(&self);
-    
-    fn part2(&self);
+    let guesses_path = inputs::guesses_path(year);
+    let mut guesses = match Guesses::load(&guesses_path) {
+        Ok(guesses) => guesses,
+        Err(error) => {
+            eprintln!("error: {}: {}", guesses_path.display(), error);
+            return false;
+        },
+    };
+    if let Some(reason) = guesses.check(day, part, &answer) {
+        eprintln!("error: not submitting, {}", reason);
+        return false;
+    }
+
+    let outcome = match Client::from_env(&cli.base_url).and_then(|client| client.submit(year, day, part, &answer)) {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            eprintln!("error: day {} part {}: {}", day, part, error);
+            return false;
+        },
+    };
+    println!("{}", outcome);
+
+    if guesses.record(day, part, &answer, &outcome) {
+        if let Err(error) = guesses.save(&guesses_path) {
+            eprintln!("error: {}: {}", guesses_path.display(), error);
+        }
+    }
+    if outcome == Outcome::Correct {
+        let answers_path = inputs::answers_path(year);
+        if let Err(error) = answers::save_answer(&answers_path, day, part, &answer) {
+            eprintln!("error: {}: {}", answers_path.display(), error);
+        }
+    }
+
+    outcome == Outcome::Correct
 }
 
-pub struct DaySolver<T, R1, R2> {
-    parser: fn(io::BufReader<File>) -> T,
-    solver1: fn(&T) -> R1,
-    solver2: fn(&T) -> R2,
-    data: T,
+/// Answer of a part for the personal input, as it is submitted.
+fn solve_for_submit(year: u16, day: u8, part: u8, cli: &Cli) -> Result<String, Error> {
+    let path = input_path(year, day, cli)?;
+    let result = runner::solve_day(year, day, DEFAULT_INPUT, Some(&path), Some(part), false, cli.timeout)?;
+    result.parse?;
+    let answer = result.parts.into_iter().next().ok_or(Error::UnknownPart(part))?.answer?;
+    match answer {
+        Answer::Integer(_) | Answer::BigInteger(_) | Answer::Text(_) => Ok(answer.to_string()),
+        Answer::Grid(_) => Err(Error::invalid_puzzle(format!("the answer is a drawing to read:\n{}", answer))),
+        Answer::NotApplicable | Answer::NoSolution => Err(Error::invalid_puzzle(format!("nothing to submit, {}", answer))),
+    }
 }
 
-impl<T, R1, R2> DaySolver<T, R1, R2> 
-    where T: Default,
-          R1: fmt::Display,
-          R2: fmt::Display,
-{
-    fn from(parser: fn(io::BufReader<File>) -> T, solver1: fn(&T) -> R1, solver2: fn(&T) -> R2) -> DaySolver<T, R1, R2> {
-        DaySolver {
-            parser,
-            solver1,
-            solver2,
-            data: Default::default(),
-        }
+/// Solve the selected parts of a day and print the results, '-' reads the input from stdin.
+fn run_day(year: u16, day: u8, part: Option<u8>, input: Option<&Path>, cli: &Cli) -> bool {
+    let path = match input {
+        None => match input_path(year, day, cli) {
+            Ok(path) => Some(path),
+            Err(error) => {
+                eprintln!("error: day {}: {}", day, error);
+                return false;
+            },
+        },
+        Some(path) if path == Path::new("-") => None,
+        Some(path) => Some(path.to_path_buf()),
+    };
+    let input_name = path.as_ref().map_or(String::from("<stdin>"), |path| path.display().to_string());
+    // Only the named inputs have known answers
+    let all_answers = if input.is_none() { Some(load_answers(year)) } else { None };
+    let answers = all_answers.as_ref().map(|answers| answers.get(day, &cli.variant));
+
+    if cli.format == Format::Json {
+        let name = if input.is_none() { cli.variant.as_str() } else { input_name.as_str() };
+        return match runner::solve_day(year, day, name, path.as_deref(), part, false, cli.timeout) {
+            Ok(mut result) => {
+                if let Some(answers) = answers {
+                    result.check(answers);
+                }
+                runner::print_errors(&result, &input_name);
+                runner::print_json(&result);
+                result.success()
+            },
+            Err(error) => {
+                eprintln!("error: {}", error);
+                false
+            },
+        };
     }
 
-    pub fn solve_part1(&self) -> R1 {
-        (self.solver1)(&self.data)
+    let puzzle = match get_puzzle(year, day) {
+        Ok(puzzle) => puzzle,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return false;
+        }
+    };
+    if !cli.quiet {
+        println!("### {} day {}: {} ###", year, day, puzzle.title);
     }
 
-    pub fn solve_part2(&self) -> R2 {
-        (self.solver2)(&self.data)
+    match run((puzzle.solver)(), day, path.as_deref(), &input_name, part, answers, cli) {
+        Ok(success) => success,
+        Err(error) => {
+            eprintln!("error: day {}: {}: {}", day, input_name, error);
+            false
+        },
     }
 }
 
-impl<T, R1, R2> Solver for DaySolver<T, R1, R2> 
-    where R1: fmt::Display,
-          R2: fmt::Display
-{
-    fn parse(&mut self, input_file: io::BufReader<File>) {
-        self.data = (self.parser)(input_file);
+/// Returns false when an answer doesn't match the expected one or a part times out.
+fn run(mut solver: Box<dyn Solver>, day: u8, path: Option<&Path>, input_name: &str, part: Option<u8>, answers: Option<&InputAnswers>, cli: &Cli) -> Result<bool, Error> {
+    let quiet = cli.quiet;
+    {
+        if !quiet {
+            println!("### Parsing input ###");
+            println!("Reading: {}", input_name);
+        }
+        memory::start();
+        let start = Instant::now();
+        let mut input: Box<dyn BufRead> = match path {
+            Some(path) => Box::new(io::BufReader::new(File::open(path)?)),
+            None => Box::new(io::stdin().lock()),
+        };
+        solver.parse(&mut input)?;
+        let duration = start.elapsed();
+        let usage = memory::usage();
+        if !quiet {
+            println!("Took {}", fmt_dur(duration));
+            print_usage(usage);
+        }
     }
 
-    fn part1(&self) {
-        let result = (self.solver1)(&self.data);
-        println!("Result: {}", result);
+    let solver: Arc<dyn Solver> = Arc::from(solver);
+    let mut success = true;
+    for current_part in 1..=2 {
+        if part.is_some_and(|part| part != current_part) {
+            continue;
+        }
+
+        if !quiet {
+            println!();
+            println!("### Running Part {} ###", current_part);
+        }
+        let solved = runner::solve_part(&solver, current_part, cli.timeout);
+        let duration = solved.time;
+        let result = match solved.answer {
+            Ok(result) => result,
+            Err(error @ Error::Timeout(_)) => {
+                eprintln!("error: day {} part {}: {}", day, current_part, error);
+                success = false;
+                continue;
+            },
+            Err(error) => return Err(error),
+        };
+        let verdict = answers.map(|answers| answers.check(current_part, &result));
+        if quiet {
+            println!("{}", result);
+        } else if let Answer::Grid(_) = result {
+            println!("Result:");
+            println!("{}", result);
+        } else {
+            println!("Result: {}", result);
+            if let Some(verdict) = verdict {
+                println!("Check: {}", verdict);
+            }
+            println!("Took {}", fmt_dur(duration));
+            print_usage(solved.memory);
+        }
+
+        if verdict == Some(Verdict::Fail) {
+            let expected = answers.and_then(|answers| answers.expected(current_part)).unwrap_or_default();
+            eprintln!("error: day {} part {}: wrong answer, expected {}", day, current_part, expected.trim());
+            success = false;
+        }
+    }
+
+    Ok(success)
+}
+
+fn print_usage(usage: Option<memory::Usage>) {
+    if let Some(usage) = usage {
+        println!("Memory: {} peak, {} allocations", fmt_bytes(usage.peak), usage.allocations);
     }
-    
-    fn part2(&self) {
-        let result = (self.solver2)(&self.data);
-        println!("Result: {}", result);
+}
+
+fn fmt_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
     }
+
+    if unit == 0 { format!("{} B", bytes) } else { format!("{:.1} {}", size, units[unit]) }
 }
 
 fn fmt_dur(dur: Duration) -> String {
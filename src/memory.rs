@@ -0,0 +1,92 @@
+use serde::Serialize;
+
+/// Heap usage of a phase (parse, part1 or part2), measured on the thread that ran it.
+#[derive(Copy, Clone)]
+#[derive(Serialize)]
+pub struct Usage {
+    /// Highest number of bytes allocated at once since the start of the phase.
+    pub peak: usize,
+    /// Number of allocations and reallocations.
+    pub allocations: usize,
+}
+
+/// Start measuring the heap usage of the current thread.
+pub fn start() {
+    #[cfg(feature = "alloc-stats")]
+    counting::reset();
+}
+
+/// Heap usage of the current thread since `start`, only known with the `alloc-stats` feature.
+pub fn usage() -> Option<Usage> {
+    #[cfg(feature = "alloc-stats")]
+    return Some(counting::usage());
+    #[cfg(not(feature = "alloc-stats"))]
+    None
+}
+
+/// The counting allocator keeps its counters per thread, so that the days and parts solved
+/// in parallel don't count the allocations of each other.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use super::Usage;
+
+    struct Counting;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    thread_local! {
+        /// Bytes allocated by the thread minus the ones it freed, negative when it frees older memory.
+        static CURRENT: Cell<isize> = const { Cell::new(0) };
+        static PEAK: Cell<isize> = const { Cell::new(0) };
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// The counters can't be reached while the thread is being destroyed, its last allocations are not counted.
+    fn record(allocated: isize, is_allocation: bool) {
+        let _ = CURRENT.try_with(|current| {
+            current.set(current.get() + allocated);
+            PEAK.with(|peak| peak.set(peak.get().max(current.get())));
+            if is_allocation {
+                ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+            }
+        });
+    }
+
+    pub fn reset() {
+        CURRENT.with(|current| current.set(0));
+        PEAK.with(|peak| peak.set(0));
+        ALLOCATIONS.with(|allocations| allocations.set(0));
+    }
+
+    pub fn usage() -> Usage {
+        Usage {
+            peak: PEAK.with(Cell::get) as usize,
+            allocations: ALLOCATIONS.with(Cell::get),
+        }
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record(layout.size() as isize, true);
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record(layout.size() as isize, true);
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record(new_size as isize - layout.size() as isize, true);
+            System.realloc(ptr, layout, new_size)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            record(-(layout.size() as isize), false);
+            System.dealloc(ptr, layout)
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::helpers::{Map, Position};
+
+/// Width of most glyphs, some of them being wider.
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+
+/// The 6 pixels high font used by the puzzles drawing letters, with `#` for lit pixels.
+/// Glyphs are separated by an empty column.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Read the letters drawn with `#` in a map, starting from its left side on the first lit row.
+/// Returns None when there are no letters or when a glyph is not part of the font.
+pub fn read_letters(map: &Map<char>) -> Option<String> {
+    let is_lit = |x: usize, y: usize| map.get(Position::new(x as i32, y as i32)) == Some(&'#');
+
+    let top = (0..map.height).find(|&y| (0..map.width).any(|x| is_lit(x, y)))?;
+    if top + GLYPH_HEIGHT > map.height {
+        return None;
+    }
+
+    let mut letters = String::new();
+    let mut left = 0;
+    while left + GLYPH_WIDTH <= map.width {
+        let glyph = |width: usize| -> Vec<String> {
+            (top..top + GLYPH_HEIGHT)
+                .map(|y| (left..left + width).map(|x| if is_lit(x, y) { '#' } else { '.' }).collect())
+                .collect()
+        };
+        if glyph(GLYPH_WIDTH).iter().all(|row| !row.contains('#')) {
+            break;
+        }
+
+        let (letter, rows) = FONT.iter().find(|(_, rows)| rows.iter().zip(glyph(rows[0].len())).all(|(row, read)| *row == read))?;
+        letters.push(*letter);
+        left += rows[0].len() + 1;
+    }
+
+    // Lit pixels outside of the letters mean that the drawing is not only text
+    let in_letters = |x: usize, y: usize| y >= top && y < top + GLYPH_HEIGHT && x < left;
+    let stray = (0..map.height).any(|y| (0..map.width).any(|x| is_lit(x, y) && !in_letters(x, y)));
+    if letters.is_empty() || stray {
+        return None;
+    }
+
+    Some(letters)
+}
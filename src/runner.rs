@@ -0,0 +1,406 @@
+use std::any::Any;
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use aoc::{deadline, get_solvers, Answer, Solver};
+use aoc::answers::{InputAnswers, Verdict};
+use aoc::error::Error;
+use crate::{fmt_bytes, fmt_dur};
+use crate::memory::{self, Usage};
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Result<Answer, Error>,
+    pub time: Duration,
+    /// Heap usage, only measured with the `alloc-stats` feature.
+    pub memory: Option<Usage>,
+    /// Comparison with the expected answer, only for checked inputs.
+    pub verdict: Option<Verdict>,
+}
+
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the input, or its path when it's not a named input of the day.
+    pub input: String,
+    pub parse: Result<Duration, Error>,
+    pub parse_memory: Option<Usage>,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    pub fn total_time(&self) -> Duration {
+        let parse_time = self.parse.as_ref().map_or(Duration::ZERO, |&time| time);
+        parse_time + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+
+    pub fn success(&self) -> bool {
+        self.parse.is_ok() && self.parts.iter().all(|part| part.answer.is_ok() && part.verdict != Some(Verdict::Fail))
+    }
+
+    pub fn check(&mut self, answers: &InputAnswers) {
+        for part in &mut self.parts {
+            if let Ok(answer) = &part.answer {
+                part.verdict = Some(answers.check(part.part, answer));
+            }
+        }
+    }
+}
+
+/// Step of a solver running on its own thread, so that the runner can give up on it after its time budget.
+struct PendingStep<T> {
+    start: Instant,
+    timeout: Option<Duration>,
+    receiver: Receiver<(Result<T, Error>, Duration, Option<Usage>)>,
+}
+
+impl<T> PendingStep<T>
+    where T: Send + 'static
+{
+    fn spawn(timeout: Option<Duration>, step: impl FnOnce() -> Result<T, Error> + Send + 'static) -> PendingStep<T> {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            deadline::start(timeout);
+            memory::start();
+            let start = Instant::now();
+            let result = catch_panic(step);
+            let time = start.elapsed();
+            // Nobody is waiting anymore when the step timed out
+            let _ = sender.send((result, time, memory::usage()));
+        });
+        PendingStep { start: Instant::now(), timeout, receiver }
+    }
+
+    /// Wait for the result until the time budget is spent. A solver that doesn't check its
+    /// deadline keeps running in the background after that, until the end of the program.
+    fn wait(self) -> (Result<T, Error>, Duration, Option<Usage>) {
+        match self.timeout {
+            Some(timeout) => match self.receiver.recv_timeout(timeout.saturating_sub(self.start.elapsed())) {
+                Ok(solved) => solved,
+                Err(RecvTimeoutError::Timeout) => (Err(Error::Timeout(timeout)), self.start.elapsed(), None),
+                Err(RecvTimeoutError::Disconnected) => (Err(stopped()), self.start.elapsed(), None),
+            },
+            None => self.receiver.recv().unwrap_or_else(|_| (Err(stopped()), self.start.elapsed(), None)),
+        }
+    }
+}
+
+struct PendingPart {
+    part: u8,
+    step: PendingStep<Answer>,
+}
+
+impl PendingPart {
+    fn spawn(solver: &Arc<dyn Solver>, part: u8, timeout: Option<Duration>) -> PendingPart {
+        let solver = Arc::clone(solver);
+        let step = PendingStep::spawn(timeout, move || if part == 1 { solver.part1() } else { solver.part2() });
+        PendingPart { part, step }
+    }
+
+    fn wait(self) -> PartResult {
+        let (answer, time, memory) = self.step.wait();
+        PartResult { part: self.part, answer, time, memory, verdict: None }
+    }
+}
+
+/// Run a step of a solver, turning its panic into an error so that the other days are still solved.
+/// The panic message is still printed by the panic hook.
+fn catch_panic<T>(step: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    panic::catch_unwind(AssertUnwindSafe(step)).unwrap_or_else(|payload| Err(Error::Panic(panic_message(payload))))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or(String::from("unknown cause"), |message| message.to_string()),
+    }
+}
+
+/// Error of a solver thread that stopped without answering.
+fn stopped() -> Error {
+    Error::Panic(String::from("the solver thread stopped without an answer"))
+}
+
+/// Run a step of a solver on its own thread like the parts, giving up after the time budget if
+/// there is one. Returns its result and the time it took.
+pub fn run_step<T>(timeout: Option<Duration>, step: impl FnOnce() -> Result<T, Error> + Send + 'static) -> (Result<T, Error>, Duration)
+    where T: Send + 'static
+{
+    let (result, time, _) = PendingStep::spawn(timeout, step).wait();
+    (result, time)
+}
+
+/// Solve a part of a parsed input, giving up after the time budget if there is one.
+pub fn solve_part(solver: &Arc<dyn Solver>, part: u8, timeout: Option<Duration>) -> PartResult {
+    PendingPart::spawn(solver, part, timeout).wait()
+}
+
+/// Parse the input of a day then solve the selected parts, recording answers and timings.
+/// Without a path the input is read from stdin. Both parts can be solved concurrently.
+pub fn solve_day(year: u16, day: u8, input: &str, path: Option<&Path>, part: Option<u8>, parallel_parts: bool, timeout: Option<Duration>) -> Result<DayResult, Error> {
+    let mut solver = get_solvers(year, day)?;
+
+    memory::start();
+    let start = Instant::now();
+    let reader: Result<Box<dyn BufRead>, Error> = match path {
+        Some(path) => File::open(path).map(|file| Box::new(io::BufReader::new(file)) as Box<dyn BufRead>).map_err(Error::from),
+        None => Ok(Box::new(io::stdin().lock())),
+    };
+    let parse = reader
+        .and_then(|mut reader| catch_panic(|| solver.parse(&mut reader)))
+        .map(|_| start.elapsed());
+    let parse_memory = memory::usage();
+
+    let solver: Arc<dyn Solver> = Arc::from(solver);
+    let selected = (1..=2).filter(|&current| part.map_or(true, |part| part == current));
+    let parts = if parse.is_err() {
+        Vec::new()
+    } else if parallel_parts {
+        let pending: Vec<PendingPart> = selected.map(|part| PendingPart::spawn(&solver, part, timeout)).collect();
+        pending.into_iter().map(PendingPart::wait).collect()
+    } else {
+        selected.map(|part| solve_part(&solver, part, timeout)).collect()
+    };
+
+    Ok(DayResult { year, day, input: input.to_string(), parse, parse_memory, parts })
+}
+
+/// Solve the input files of several days with `jobs` threads, each taking the next unsolved day.
+/// The results are in the order of the days, whatever the order they were solved in.
+pub fn solve_days(year: u16, input: &str, days: &[(u8, PathBuf)], jobs: usize, parallel_parts: bool, timeout: Option<Duration>) -> Vec<Result<DayResult, Error>> {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, Result<DayResult, Error>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, days.len().max(1))).map(|_| scope.spawn(|| {
+            let mut results = Vec::new();
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                match days.get(index) {
+                    Some((day, path)) => results.push((index, solve_day(year, *day, input, Some(path), None, parallel_parts, timeout))),
+                    None => break,
+                }
+            }
+            results
+        })).collect();
+        workers.into_iter().flat_map(|worker| worker.join().unwrap_or_default()).collect()
+    });
+
+    // The days of a worker that stopped have no result
+    results.sort_by_key(|&(index, _)| index);
+    let mut results = results.into_iter().peekable();
+    (0..days.len()).map(|index| match results.next_if(|&(solved, _)| solved == index) {
+        Some((_, result)) => result,
+        None => Err(stopped()),
+    }).collect()
+}
+
+/// Answer as displayed in a table cell: errors and multi-line answers don't fit in a cell.
+fn answer_cell(part: Option<&PartResult>) -> String {
+    match part.map(|part| &part.answer) {
+        Some(Ok(Answer::Grid(rows))) => format!("<{} lines>", rows.len()),
+        Some(Ok(answer)) => answer.to_string(),
+        Some(Err(Error::Timeout(_))) => String::from("TIMEOUT"),
+        Some(Err(_)) => String::from("ERROR"),
+        None => String::from("-"),
+    }
+}
+
+fn verdict_cell(part: Option<&PartResult>) -> String {
+    part.and_then(|part| part.verdict).map_or(String::from("-"), |verdict| verdict.to_string())
+}
+
+fn time_cell(part: Option<&PartResult>) -> String {
+    part.map_or(String::from("-"), |part| fmt_dur(part.time))
+}
+
+pub fn print_table(results: &[DayResult]) {
+    let header = ["Day", "Part 1", "Check", "Part 2", "Check", "Parse", "Part 1", "Part 2", "Total"].map(String::from);
+    let mut rows: Vec<[String; 9]> = Vec::new();
+    for result in results {
+        let (part1, part2) = (result.parts.first(), result.parts.get(1));
+        rows.push([
+            result.day.to_string(),
+            answer_cell(part1),
+            verdict_cell(part1),
+            answer_cell(part2),
+            verdict_cell(part2),
+            result.parse.as_ref().map_or(String::from("ERROR"), |&time| fmt_dur(time)),
+            time_cell(part1),
+            time_cell(part2),
+            fmt_dur(result.total_time()),
+        ]);
+    }
+
+    let total: Duration = results.iter().map(|result| result.total_time()).sum();
+    let mut footer: [String; 9] = Default::default();
+    footer[0] = String::from("Total");
+    footer[8] = fmt_dur(total);
+
+    let mut widths = [0usize; 9];
+    for row in [&header].into_iter().chain(rows.iter()).chain([&footer]) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &[String; 9]| {
+        let cells: Vec<String> = row.iter().zip(widths.iter()).enumerate().map(|(i, (cell, &width))| {
+            if i == 0 || i >= 5 { format!("{:>width$}", cell) } else { format!("{:<width$}", cell) }
+        }).collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+
+    print_row(&header);
+    println!("{}", "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1)));
+    rows.iter().for_each(print_row);
+    println!("{}", "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1)));
+    print_row(&footer);
+}
+
+/// Peak heap usage and allocation count of each phase, when they are measured.
+pub fn print_memory_table(results: &[DayResult]) {
+    let usage_cells = |usage: Option<Usage>| match usage {
+        Some(usage) => [fmt_bytes(usage.peak), usage.allocations.to_string()],
+        None => [String::from("-"), String::from("-")],
+    };
+
+    let header = ["Day", "Parse peak", "Allocs", "Part 1 peak", "Allocs", "Part 2 peak", "Allocs"].map(String::from);
+    let mut rows: Vec<[String; 7]> = Vec::new();
+    for result in results {
+        let [parse_peak, parse_allocations] = usage_cells(result.parse_memory);
+        let [part1_peak, part1_allocations] = usage_cells(result.parts.first().and_then(|part| part.memory));
+        let [part2_peak, part2_allocations] = usage_cells(result.parts.get(1).and_then(|part| part.memory));
+        rows.push([result.day.to_string(), parse_peak, parse_allocations, part1_peak, part1_allocations, part2_peak, part2_allocations]);
+    }
+
+    let mut widths = [0usize; 7];
+    for row in [&header].into_iter().chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &[String; 7]| {
+        let cells: Vec<String> = row.iter().zip(widths.iter()).map(|(cell, &width)| format!("{:>width$}", cell)).collect();
+        println!("{}", cells.join("  "));
+    };
+
+    print_row(&header);
+    println!("{}", "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1)));
+    rows.iter().for_each(print_row);
+}
+
+/// Report the errors of a day on stderr, the table only shows them as ERROR or TIMEOUT.
+pub fn print_errors(result: &DayResult, input_name: &str) {
+    if let Err(error) = &result.parse {
+        eprintln!("error: day {}: {}: {}", result.day, input_name, error);
+    }
+
+    for part in &result.parts {
+        if let Err(error) = &part.answer {
+            eprintln!("error: day {} part {}: {}", result.day, part.part, error);
+        }
+    }
+}
+
+/// One line of the JSON output. Times are in seconds, the part is null when the input failed to parse.
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    input: &'a str,
+    part: Option<u8>,
+    answer: Option<&'a Answer>,
+    error: Option<String>,
+    verdict: Option<Verdict>,
+    parse_time: Option<f64>,
+    solve_time: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_memory: Option<Usage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solve_memory: Option<Usage>,
+}
+
+/// Print one JSON record per solved part, as JSON lines.
+pub fn print_json(result: &DayResult) {
+    let parse_time = result.parse.as_ref().ok().map(Duration::as_secs_f64);
+    let mut records = Vec::new();
+    match &result.parse {
+        Err(error) => records.push(Record {
+            year: result.year,
+            day: result.day,
+            input: &result.input,
+            part: None,
+            answer: None,
+            error: Some(error.to_string()),
+            verdict: None,
+            parse_time,
+            solve_time: None,
+            parse_memory: result.parse_memory,
+            solve_memory: None,
+        }),
+        Ok(_) => {
+            for part in &result.parts {
+                records.push(Record {
+                    year: result.year,
+                    day: result.day,
+                    input: &result.input,
+                    part: Some(part.part),
+                    answer: part.answer.as_ref().ok(),
+                    error: part.answer.as_ref().err().map(Error::to_string),
+                    verdict: part.verdict,
+                    parse_time,
+                    solve_time: Some(part.time.as_secs_f64()),
+                    parse_memory: result.parse_memory,
+                    solve_memory: part.memory,
+                });
+            }
+        },
+    }
+
+    for record in records {
+        println!("{}", serde_json::to_string(&record).expect("records are serializable"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufRead;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    use aoc::{DaySolver, Solver};
+    use aoc::error::Error;
+    use super::{run_step, solve_part};
+
+    fn parser(_: &mut dyn BufRead) -> Result<u8, Error> {
+        Ok(0)
+    }
+
+    fn panicking(_: &u8) -> Result<u8, Error> {
+        panic!("no answer for {}", 1)
+    }
+
+    fn answering(_: &u8) -> Result<u8, Error> {
+        Ok(2)
+    }
+
+    #[test]
+    fn solver_panic() {
+        let solver: Arc<dyn Solver> = Arc::new(DaySolver::from(parser, panicking, answering));
+        for timeout in [None, Some(Duration::from_secs(10))] {
+            assert!(matches!(solve_part(&solver, 1, timeout).answer, Err(Error::Panic(message)) if message == "no answer for 1"));
+            assert!(matches!(solve_part(&solver, 2, timeout).answer, Ok(answer) if answer.to_string() == "2"), "Other parts are still solved");
+            assert!(matches!(run_step(timeout, || parser(&mut "".as_bytes()).map(|_| panic!("no input"))).0, Err(Error::Panic(_))), "Other steps");
+        }
+
+        let endless = run_step(Some(Duration::from_millis(10)), || parser(&mut "".as_bytes()).map(|_| thread::sleep(Duration::from_secs(1))));
+        assert!(matches!(endless.0, Err(Error::Timeout(_))), "Endless step");
+    }
+}
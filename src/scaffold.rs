@@ -0,0 +1,96 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use aoc::answers;
+use aoc::error::Error;
+use aoc::inputs;
+
+/// Directory of the solver modules, relative to the root of the crate.
+const SOLVERS_DIR: &str = "src/solvers";
+
+/// Create the solver module of a new day in the crate at `root`, where `build.rs` registers it,
+/// with an empty example input and the day marked as pending in the answers file.
+/// Returns the created or updated files, relative to the root.
+pub fn new_day(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, Error> {
+    let solvers_dir = Path::new(SOLVERS_DIR);
+    if !root.join(solvers_dir).is_dir() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, format!("{} not found, run from the root of the crate", SOLVERS_DIR)).into());
+    }
+
+    let module_path = solvers_dir.join(format!("y{}", year)).join(format!("day{:02}.rs", day));
+    if root.join(&module_path).exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", module_path.display())).into());
+    }
+
+    let mut changed = Vec::new();
+    fs::create_dir_all(root.join(solvers_dir).join(format!("y{}", year)))?;
+    fs::write(root.join(&module_path), module(day, title))?;
+    changed.push(module_path);
+
+    let example_path = inputs::input_path(year, day, "example");
+    if !root.join(&example_path).exists() {
+        fs::create_dir_all(root.join(inputs::day_dir(year, day)))?;
+        fs::write(root.join(&example_path), "")?;
+        changed.push(example_path);
+    }
+
+    let answers_path = inputs::answers_path(year);
+    answers::save_pending(root.join(&answers_path), day)?;
+    changed.push(answers_path);
+
+    Ok(changed)
+}
+
+fn module(day: u8, title: &str) -> String {
+    format!(r#"use std::io::BufRead;
+use crate::error::Error;
+use crate::helpers;
+
+puzzle!({}, {:?});
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<String>, Error> {{
+    helpers::parse_file_to_list(input, |line| Ok(line.to_string()))
+}}
+
+pub fn part1(_input: &Vec<String>) -> Result<usize, Error> {{
+    Err(Error::invalid_puzzle("part 1 is not solved yet"))
+}}
+
+pub fn part2(_input: &Vec<String>) -> Result<usize, Error> {{
+    Err(Error::invalid_puzzle("part 2 is not solved yet"))
+}}
+"#, day, title)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use aoc::answers::Answers;
+    use aoc::error::Error;
+    use aoc::inputs;
+    use super::new_day;
+
+    #[test]
+    fn scaffold() -> Result<(), Error> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/solvers"))?;
+        let read = |path: &str| fs::read_to_string(root.join(path));
+
+        let changed = new_day(&root, 2021, 2, "Dive!")?;
+        assert_eq!(changed.len(), 3, "Module, example and answers");
+        assert!(read("src/solvers/y2021/day02.rs")?.contains("puzzle!(2, \"Dive!\");"));
+        assert!(root.join(inputs::input_path(2021, 2, "example")).exists());
+        assert!(Answers::load(root.join(inputs::answers_path(2021)))?.is_pending(2));
+
+        let module = read("src/solvers/y2021/day02.rs")?;
+        assert!(new_day(&root, 2021, 2, "Dive!").is_err(), "Existing day");
+        assert_eq!(read("src/solvers/y2021/day02.rs")?, module, "Nothing changed by the second run");
+
+        new_day(&root, 2022, 1, "Calorie Counting")?;
+        assert!(root.join("src/solvers/y2022/day01.rs").exists(), "First day of a year");
+
+        fs::remove_dir_all(&root)?;
+        assert!(new_day(&root, 2021, 4, "Giant Squid").is_err(), "Not the root of a crate");
+        Ok(())
+    }
+}
@@ -1,25 +1,16 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+/// Register the solver of a day and its metadata, from the module of the day.
+/// The module must define the `parser`, `part1` and `part2` functions.
+macro_rules! puzzle {
+    ($day:expr, $title:expr) => {
+        pub const PUZZLE: crate::Puzzle = crate::Puzzle {
+            year: super::YEAR,
+            day: $day,
+            title: $title,
+            solver: || Box::new(crate::DaySolver::from(parser, part1, part2)),
+        };
+    };
+}
+
+// The `y<year>` modules with the `dayNN` modules of their days, their `PUZZLES` and the `YEARS`
+// list, generated by `build.rs` from the `src/solvers/y<year>/dayNN.rs` files.
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
@@ -1,12 +1,14 @@
-use std::fs::File;
-use std::io;
+use std::io::BufRead;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<i32> {
-    helpers::parse_file_to_list(input_file, |line| { line.parse().unwrap() })
+puzzle!(1, "Sonar Sweep");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<i32>, Error> {
+    helpers::parse_file_to_list(input, |line| { helpers::parse_value(line, 1) })
 }
 
-pub fn part1(input: &Vec<i32>) -> i32 {
+pub fn part1(input: &Vec<i32>) -> Result<i32, Error> {
     let mut increased = 0;
     input.iter().enumerate().for_each(|(i, &x)| {
         if i > 0 && x > input[i-1] {
@@ -14,10 +16,10 @@ pub fn part1(input: &Vec<i32>) -> i32 {
         }
     });
 
-    increased
+    Ok(increased)
 }
 
-pub fn part2(input: &Vec<i32>) -> i32 {
+pub fn part2(input: &Vec<i32>) -> Result<i32, Error> {
     let mut windows: [(i32, i32); 3] = [(0, 0), (0, 0), (0, 0)];
     let mut windows_count: usize = 0;
     let mut current_window: usize = 0;
@@ -35,7 +37,7 @@ pub fn part2(input: &Vec<i32>) -> i32 {
         }
 
         if windows[current_window].0 == 3 {
-            if i > 3 && windows[current_window].1 > last {
+            if i >= 3 && windows[current_window].1 > last {
                 increased += 1;
             }
 
@@ -45,5 +47,5 @@ pub fn part2(input: &Vec<i32>) -> i32 {
         }
     });
 
-    increased
+    Ok(increased)
 }
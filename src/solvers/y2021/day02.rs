@@ -1,28 +1,29 @@
-use std::fs::File;
-use std::io;
+use std::io::BufRead;
 use regex::Regex;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<Command> {
+puzzle!(2, "Dive!");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<Command>, Error> {
     let regex = Regex::new(r"^(forward|down|up)\s([0-9]+)$").unwrap();
-    helpers::parse_file_to_list(input_file, |line| {
-        let capture = regex.captures(line).unwrap();
-        let instruction = match capture.get(1).unwrap().as_str() {
+    helpers::parse_file_to_list(input, |line| {
+        let capture = helpers::parse_regex(&regex, line)?;
+        let instruction = match &capture[1] {
             "forward" => Instruction::Forward,
             "down" => Instruction::Down,
-            "up" => Instruction::Up,
-            _ => panic!("unknown instruction"),
+            _ => Instruction::Up,
         };
 
-        let distance = capture.get(2).unwrap().as_str().parse::<i32>().unwrap();
-        Command {
+        let distance = helpers::parse_capture(&capture, 2)?;
+        Ok(Command {
             instruction,
             distance,
-        }
+        })
     })
 }
 
-pub fn part1(input: &Vec<Command>) -> i32 {
+pub fn part1(input: &Vec<Command>) -> Result<i32, Error> {
     let mut depth = 0;
     let mut position = 0;
     for command in input {
@@ -32,13 +33,11 @@ pub fn part1(input: &Vec<Command>) -> i32 {
             Instruction::Up => depth -= command.distance,
         }
     };
-    
-    println!("Final position: {} depth: {}", position, depth);
 
-    position * depth
+    Ok(position * depth)
 }
 
-pub fn part2(input: &Vec<Command>) -> i32 {
+pub fn part2(input: &Vec<Command>) -> Result<i32, Error> {
     let mut depth = 0;
     let mut position = 0;
     let mut aim = 0;
@@ -52,10 +51,8 @@ pub fn part2(input: &Vec<Command>) -> i32 {
             Instruction::Up => aim -= command.distance,
         }
     };
-    
-    println!("Final position: {} depth: {} aim: {}", position, depth, aim);
 
-    position * depth
+    Ok(position * depth)
 }
 
 enum Instruction {
@@ -0,0 +1,138 @@
+use std::io::BufRead;
+use crate::error::Error;
+use crate::helpers;
+
+puzzle!(3, "Binary Diagnostic");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<String>, Error> {
+    let inputs = helpers::parse_file_to_list(input, |line| {
+        if line.is_empty() {
+            return Err(Error::parse(1, "expected a binary number"));
+        }
+
+        if let Some(index) = line.find(|c| c != '0' && c != '1') {
+            return Err(Error::parse(index + 1, "expected a binary digit"));
+        }
+
+        Ok(String::from(line))
+    })?;
+
+    if let Some(first) = inputs.first() {
+        if let Some(index) = inputs.iter().position(|input| input.len() != first.len()) {
+            return Err(Error::parse(1, format!("expected {} bits", first.len())).at_line(index + 1));
+        }
+    }
+
+    Ok(inputs)
+}
+
+pub fn part1(input: &Vec<String>) -> Result<i32, Error> {
+    if input.is_empty() {
+        return Err(Error::invalid_puzzle("no diagnostic report"));
+    }
+
+    let half = input.len() as i32 / 2;
+
+    let mut count: Vec<i32> = vec![0; input[0].len()];
+    for input in input {
+        input.chars().enumerate().for_each(|(i, c)| {
+            if c == '0' {
+                count[i] += 1;
+            }
+        });
+    }
+
+    let mut gamma_string = String::new();
+    let mut epsilon_string = String::new();
+    for c in count {
+        if c > half {
+            gamma_string.push('0');
+            epsilon_string.push('1');
+        } else {
+            gamma_string.push('1');
+            epsilon_string.push('0');
+        }
+    }
+
+    let gamma = parse_binary(&gamma_string)?;
+    let epsilon = parse_binary(&epsilon_string)?;
+
+    Ok(gamma * epsilon)
+}
+
+pub fn part2(input: &Vec<String>) -> Result<i32, Error> {
+    if input.is_empty() {
+        return Err(Error::invalid_puzzle("no diagnostic report"));
+    }
+
+    // Oxygen: bit with most common value (1 if equal)
+    let mut values = input.clone();
+    let mut index = 0;
+    loop {
+        if index >= input[0].len() {
+            return Err(Error::invalid_puzzle("duplicated oxygen generator ratings"));
+        }
+
+        let half = values.len() as i32 / 2;
+        let mut count: i32 = 0;
+        for value in &values {
+            if value.chars().nth(index).unwrap() == '0' {
+                count += 1;
+            }
+        }
+
+        let wanted = if count > half { '0' } else { '1' };
+        for i in (0..values.len()).rev() {
+            if values[i].chars().nth(index).unwrap() != wanted {
+                values.remove(i);
+            }
+        }
+
+        index += 1;
+        if values.len() <= 1 {
+            break;
+        }
+    }
+
+    let oxygen_string = values.last().ok_or_else(|| Error::invalid_puzzle("no oxygen generator rating"))?;
+    
+    // CO2: bit with least common value (0 if equal)
+    let mut values = input.clone();
+    let mut index = 0;
+    loop {
+        if index >= input[0].len() {
+            return Err(Error::invalid_puzzle("duplicated CO2 scrubber ratings"));
+        }
+
+        let half = values.len() as i32 / 2;
+        let mut count: i32 = 0;
+        for value in &values {
+            if value.chars().nth(index).unwrap() == '0' {
+                count += 1;
+            }
+        }
+
+        let wanted = if count <= half { '0' } else { '1' };
+        for i in (0..values.len()).rev() {
+            if values[i].chars().nth(index).unwrap() != wanted {
+                values.remove(i);
+            }
+        }
+
+        index += 1;
+        if values.len() <= 1 {
+            break;
+        }
+    }
+
+    let co2_string = values.last().ok_or_else(|| Error::invalid_puzzle("no CO2 scrubber rating"))?;
+
+    let oxygen = parse_binary(oxygen_string)?;
+    let co2 = parse_binary(co2_string)?;
+
+    Ok(oxygen * co2)
+}
+
+fn parse_binary(value: &str) -> Result<i32, Error> {
+    i32::from_str_radix(value, 2).map_err(|error| Error::invalid_puzzle(format!("invalid rating '{}': {}", value, error)))
+}
@@ -1,10 +1,12 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::fmt::{Display, Formatter, Result};
+use std::io::BufRead;
+use std::fmt::{self, Display, Formatter};
 use regex::Regex;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> (Vec<u8>, Vec<helpers::Map<Cell>>) {
+puzzle!(4, "Giant Squid");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<(Vec<u8>, Vec<helpers::Map<Cell>>), Error> {
     let regex = Regex::new(r"^\s*([0-9]+)\s*([0-9]+)\s*([0-9]+)\s*([0-9]+)\s*([0-9]+)\s*$").unwrap();
     let mut numbers: Vec<u8> = Vec::new();
     let mut grids: Vec<helpers::Map<Cell>> = Vec::new();
@@ -12,36 +14,45 @@ pub fn parser(input_file: io::BufReader<File>) -> (Vec<u8>, Vec<helpers::Map<Cel
     {
         let mut grid: helpers::Map<Cell> = helpers::Map::new(5, 5);
         let mut y = 0;
-        for line in input_file.lines() {
-            if let Ok(ip) = line {
-                if numbers.len() == 0 {
-                    numbers = ip.split(',').map(|str| str.parse::<u8>().unwrap()).collect();  
-                } else {
-                    if let Option::Some(capture) = regex.captures(&ip) {
-                        let mut x = 0;
-                        for i in 1..capture.len() {
-                            let cell = grid.get_mut(helpers::Position { x, y }).unwrap();
-                            cell.number = capture.get(i).unwrap().as_str().parse::<u8>().unwrap();
-                            x += 1;
-                        }
-
-                        y += 1;
-                        if y == 5 {
-                            // New grid
-                            grids.push(grid);
-                            grid = helpers::Map::new(5, 5);
-                            y = 0;
-                        }
+        for (index, line) in input.lines().enumerate() {
+            let ip = line?;
+            if numbers.is_empty() {
+                let mut column = 1;
+                for str in ip.split(',') {
+                    numbers.push(helpers::parse_value(str, column).map_err(|error| error.at_line(index + 1))?);
+                    column += str.len() + 1;
+                }
+            } else {
+                if let Option::Some(capture) = regex.captures(&ip) {
+                    let mut x = 0;
+                    for i in 1..capture.len() {
+                        let cell = grid.get_mut(helpers::Position { x, y }).unwrap();
+                        cell.number = helpers::parse_capture(&capture, i).map_err(|error| error.at_line(index + 1))?;
+                        x += 1;
+                    }
+
+                    y += 1;
+                    if y == 5 {
+                        // New grid
+                        grids.push(grid);
+                        grid = helpers::Map::new(5, 5);
+                        y = 0;
                     }
+                } else if !ip.trim().is_empty() {
+                    return Err(Error::parse(1, "expected a row of 5 numbers").at_line(index + 1));
                 }
             }
         }
+
+        if y != 0 {
+            return Err(Error::invalid_puzzle("last grid is incomplete"));
+        }
     }
 
-    (numbers, grids)
+    Ok((numbers, grids))
 }
 
-pub fn part1(input: &(Vec<u8>, Vec<helpers::Map<Cell>>)) -> i32 {
+pub fn part1(input: &(Vec<u8>, Vec<helpers::Map<Cell>>)) -> Result<Option<i32>, Error> {
     let numbers = &input.0;
     let mut maps = input.1.clone();
 
@@ -52,35 +63,32 @@ pub fn part1(input: &(Vec<u8>, Vec<helpers::Map<Cell>>)) -> i32 {
             let (victory, unmarked_sum) = map.check_victory();
             if victory {
                 let first_to_win = n as i32 * unmarked_sum;
-                println!("First grid to win: {}", first_to_win);
-                return first_to_win;
+                return Ok(Some(first_to_win));
             }
         }
     }
 
-    println!("Can find any winning grid");
-    -1
+    Ok(None)
 }
 
-pub fn part2(input: &(Vec<u8>, Vec<helpers::Map<Cell>>)) -> i32 {
+pub fn part2(input: &(Vec<u8>, Vec<helpers::Map<Cell>>)) -> Result<Option<i32>, Error> {
     let numbers = &input.0;
     let mut maps = input.1.clone();
 
-    let mut last_to_win = -1;
+    let mut last_to_win = None;
     for &n in numbers {
         for i in (0..maps.len()).rev() {
             let map = &mut maps[i];
             map.mark(n);
             let (victory, unmarked_sum) = map.check_victory();
             if victory {
-                last_to_win = n as i32 * unmarked_sum;
+                last_to_win = Some(n as i32 * unmarked_sum);
                 maps.remove(i);
             }
         }
     }
 
-    println!("Last grid to win: {}", last_to_win);
-    last_to_win
+    Ok(last_to_win)
 }
 
 impl helpers::Map<Cell> {
@@ -116,7 +124,7 @@ pub struct Cell {
 }
 
 impl Display for Cell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{:02}{}", self.number, if self.marked { "x" } else { "." })
     }
 }
@@ -1,61 +1,56 @@
-use std::fs::File;
-use std::io;
+use std::io::BufRead;
 use regex::Regex;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<Line> {
+puzzle!(5, "Hydrothermal Venture");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<Line>, Error> {
     let regex = Regex::new(r"^([0-9]+),([0-9]+)\s->\s([0-9]+),([0-9]+)$").unwrap();
-    helpers::parse_file_to_list(input_file, |line| { 
-        let capture = regex.captures(line).unwrap();
-        Line {
+    helpers::parse_file_to_list(input, |line| { 
+        let capture = helpers::parse_regex(&regex, line)?;
+        let line = Line {
             p1: helpers::Position {
-                x: capture.get(1).unwrap().as_str().parse::<i32>().unwrap(),
-                y: capture.get(2).unwrap().as_str().parse::<i32>().unwrap(),
+                x: helpers::parse_capture(&capture, 1)?,
+                y: helpers::parse_capture(&capture, 2)?,
             },
             p2: helpers::Position {
-                x: capture.get(3).unwrap().as_str().parse::<i32>().unwrap(),
-                y: capture.get(4).unwrap().as_str().parse::<i32>().unwrap(),
+                x: helpers::parse_capture(&capture, 3)?,
+                y: helpers::parse_capture(&capture, 4)?,
             },
+        };
+
+        let delta = line.p2 - line.p1;
+        if !line.strait() && delta.x.abs() != delta.y.abs() {
+            return Err(Error::parse(1, "line is neither straight nor diagonal"));
         }
+
+        Ok(line)
     })
 }
 
-pub fn part1(input: &Vec<Line>) -> i32 {
-    let mut width = 0;
-    let mut height = 0;
-    input.iter().for_each(|line| {
-        if line.p1.x > width { width = line.p1.x } else if line.p2.x > width { width = line.p2.x };
-        if line.p1.y > height { height = line.p1.y } else if line.p2.y > height { height = line.p2.y };
-    });
-    
-    let mut map: helpers::Map<i16> = helpers::Map::new((width + 1) as usize, (height + 1) as usize);
+pub fn part1(input: &Vec<Line>) -> Result<i32, Error> {
+    let mut map: helpers::SparseMap<i16> = helpers::SparseMap::new();
     for &line in input {
         if line.strait() {
             for p in line {
-                *map.get_mut(p).unwrap() += 1;
+                *map.get_or_insert_default(p) += 1;
             }
         }
     }
     
-    map.iter().fold(0, |count, &cell| if cell >= 2 {count + 1} else { count })
+    Ok(map.values().fold(0, |count, &cell| if cell >= 2 {count + 1} else { count }))
 }
 
-pub fn part2(input: &Vec<Line>) -> i32 {
-    let mut width = 0;
-    let mut height = 0;
-    input.iter().for_each(|line| {
-        if line.p1.x > width { width = line.p1.x } else if line.p2.x > width { width = line.p2.x };
-        if line.p1.y > height { height = line.p1.y } else if line.p2.y > height { height = line.p2.y };
-    });
-    
-    let mut map: helpers::Map<i16> = helpers::Map::new((width + 1) as usize, (height + 1) as usize);
+pub fn part2(input: &Vec<Line>) -> Result<i32, Error> {
+    let mut map: helpers::SparseMap<i16> = helpers::SparseMap::new();
     for &line in input {
         for p in line {
-            *map.get_mut(p).unwrap() += 1;
+            *map.get_or_insert_default(p) += 1;
         }
     }
     
-    map.iter().fold(0, |count, &cell| if cell >= 2 {count + 1} else { count })
+    Ok(map.values().fold(0, |count, &cell| if cell >= 2 {count + 1} else { count }))
 }
 
 #[derive(Copy, Clone)]
@@ -0,0 +1,59 @@
+use std::io::BufRead;
+use crate::error::Error;
+use crate::helpers;
+
+puzzle!(6, "Lanternfish");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<i32>, Error> {
+    let mut inputs = helpers::parse_file_to_list(input, |line| { 
+        let mut values: Vec<i32> = Vec::new();
+        let mut column = 1;
+        for str in line.split(',') {
+            values.push(helpers::parse_value(str, column)?);
+            column += str.len() + 1;
+        }
+
+        Ok(values)
+    })?;
+
+    inputs.pop().ok_or_else(|| Error::invalid_puzzle("empty input"))
+}
+
+pub fn part1(input: &Vec<i32>) -> Result<i32, Error> {
+    let mut fishes = input.clone();
+
+    for _day in 0..80 {
+        for i in (0..fishes.len()).rev() {
+            fishes[i] -= 1;
+            if fishes[i] < 0 {
+                fishes[i] = 6;
+                fishes.push(8); // new fish
+            }
+        }
+    }
+
+    Ok(fishes.len() as i32)
+}
+
+pub fn part2(input: &Vec<i32>) -> Result<i64, Error> {
+    let mut fishes = [0i64; 9];
+    for &fish in input {
+        if !(0..9).contains(&fish) {
+            return Err(Error::invalid_puzzle(format!("invalid fish timer {}", fish)));
+        }
+
+        fishes[fish as usize] += 1;
+    }
+
+    for _day in 0..256 {
+        let new_fishes = fishes[0];
+        for i in 1..fishes.len() {
+            fishes[i - 1] = fishes[i];
+        }
+
+        fishes[6] += new_fishes;
+        fishes[8] = new_fishes;
+    }
+
+    Ok(fishes.iter().fold(0, |count, &total| total + count))
+}
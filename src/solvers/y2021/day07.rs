@@ -1,17 +1,25 @@
-use std::fs::File;
-use std::io;
+use std::io::BufRead;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<i32> {
-    let mut inputs = helpers::parse_file_to_list(input_file, |line| { 
-        let values: Vec<i32> = line.split(',').map(|str| str.parse::<i32>().unwrap()).collect();
-        values
-    });
+puzzle!(7, "The Treachery of Whales");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<i32>, Error> {
+    let mut inputs = helpers::parse_file_to_list(input, |line| { 
+        let mut values: Vec<i32> = Vec::new();
+        let mut column = 1;
+        for str in line.split(',') {
+            values.push(helpers::parse_value(str, column)?);
+            column += str.len() + 1;
+        }
+
+        Ok(values)
+    })?;
 
-    inputs.pop().unwrap()
+    inputs.pop().ok_or_else(|| Error::invalid_puzzle("empty input"))
 }
 
-pub fn part1(input: &Vec<i32>) -> i32 {
+pub fn part1(input: &Vec<i32>) -> Result<i32, Error> {
     let mut min = std::i32::MAX;
     let mut max = std::i32::MIN;
     input.iter().for_each(|&p| {
@@ -27,10 +35,10 @@ pub fn part1(input: &Vec<i32>) -> i32 {
         }
     }
 
-    min_conso
+    Ok(min_conso)
 }
 
-pub fn part2(input: &Vec<i32>) -> i64 {
+pub fn part2(input: &Vec<i32>) -> Result<i64, Error> {
     let mut min = std::i32::MAX;
     let mut max = std::i32::MIN;
     input.iter().for_each(|&p| {
@@ -49,5 +57,5 @@ pub fn part2(input: &Vec<i32>) -> i64 {
         }
     }
 
-    min_conso
+    Ok(min_conso)
 }
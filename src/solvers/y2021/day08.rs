@@ -1,25 +1,26 @@
-use std::fs::File;
 use std::collections::HashMap;
-use std::io;
-use std::panic;
+use std::io::BufRead;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<Entry> {
-    let inputs = helpers::parse_file_to_list(input_file, |line| {
+puzzle!(8, "Seven Segment Search");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<Entry>, Error> {
+    let inputs = helpers::parse_file_to_list(input, |line| {
         let mut parts = line.split(" | ");
         let signals = parts.next().unwrap().split(" ").map(|signal| Digit::from(signal)).collect();
-        let outputs = parts.next().unwrap().split(" ").map(|output| Digit::from(output)).collect();
-        Entry {
+        let outputs = parts.next().ok_or_else(|| Error::parse(line.len() + 1, "missing ' | ' separator"))?.split(" ").map(|output| Digit::from(output)).collect();
+        Ok(Entry {
             signals,
             outputs,
-        }
+        })
 
-    });
+    })?;
 
-    inputs
+    Ok(inputs)
 }
 
-pub fn part1(input: &Vec<Entry>) -> i32 {
+pub fn part1(input: &Vec<Entry>) -> Result<i32, Error> {
     let mut count = 0;
     for entry in input {
         for output in &entry.outputs {
@@ -30,10 +31,10 @@ pub fn part1(input: &Vec<Entry>) -> i32 {
         }
     }
 
-    count
+    Ok(count)
 }
 
-pub fn part2(input: &Vec<Entry>) -> u32 {
+pub fn part2(input: &Vec<Entry>) -> Result<u32, Error> {
     let entries = input.clone();
 
     let mut count = 0;
@@ -76,7 +77,7 @@ pub fn part2(input: &Vec<Entry>) -> u32 {
         let mut digits_hash: HashMap<u32,u32> = HashMap::new();
         for n in 0..digits.len() {
             if digits[n].string.len() == 0 {
-                panic!("Digit {} not found", n);
+                return Err(Error::invalid_puzzle(format!("digit {} not found", n)));
             }
 
             digits_hash.insert(digits[n].hash, n as u32);
@@ -84,13 +85,14 @@ pub fn part2(input: &Vec<Entry>) -> u32 {
 
         let mut number = 0;
         for i in 0..entry.outputs.len() {
-            number += digits_hash[&entry.outputs[i].hash] * u32::pow(10, (entry.outputs.len() - i - 1) as u32);
+            let digit = digits_hash.get(&entry.outputs[i].hash).ok_or_else(|| Error::invalid_puzzle(format!("unknown output digit '{}'", entry.outputs[i].string)))?;
+            number += digit * u32::pow(10, (entry.outputs.len() - i - 1) as u32);
         }
 
         count += number;
     }
 
-    count
+    Ok(count)
 }
 
 #[derive(Clone)]
@@ -0,0 +1,52 @@
+use std::io::BufRead;
+use crate::error::Error;
+use crate::helpers::{search, FromChar, Map};
+
+puzzle!(9, "Smoke Basin");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Map<Cell>, Error> {
+    Map::parse_grid(input, |c| Ok(Cell { elevation: u32::from_char(c)?, bassin: -1 }))
+}
+
+pub fn part1(input: &Map<Cell>) -> Result<u32, Error> {
+    let mut count = 0;
+    for (pos, cell) in input.cells() {
+        if input.neighbours(pos, false).all(|(_, other)| cell.elevation < other.elevation) {
+            count += cell.elevation + 1;
+        }
+    }
+
+    Ok(count)
+}
+
+pub fn part2(input: &Map<Cell>) -> Result<i32, Error> {
+    let mut map = input.clone();
+
+    let mut bassin_index = -1;
+    let mut bassin_sizes = [0i32; 3];
+    for pos in map.positions() {
+        let cell = map.get(pos).unwrap();
+        if cell.elevation < 9 && cell.bassin < 0 {
+            bassin_index += 1;
+            let bassin = search::flood_fill(pos, |&pos| map.neighbours(pos, false).filter(|(_, cell)| cell.elevation != 9).map(|(neighbour, _)| neighbour));
+            for &bassin_pos in &bassin {
+                map.get_mut(bassin_pos).unwrap().bassin = bassin_index;
+            }
+
+            let bassin_size = bassin.len() as i32;
+            let smallest_index = if bassin_sizes[0] < bassin_sizes[1] && bassin_sizes[0] < bassin_sizes[2] { 0 } else if bassin_sizes[1] < bassin_sizes[2] { 1 } else { 2 };
+            if bassin_size > bassin_sizes[smallest_index] {
+                bassin_sizes[smallest_index] = bassin_size
+            }
+        }
+    }
+
+    Ok(bassin_sizes.iter().fold(1, |a, &b| a * b))
+}
+
+#[derive(Default)]
+#[derive(Clone, Copy)]
+pub struct Cell {
+    elevation: u32,
+    bassin: i32,
+}
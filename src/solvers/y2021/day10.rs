@@ -1,14 +1,20 @@
-use std::fs::File;
-use std::io;
+use std::io::BufRead;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<Vec<char>> {
-    helpers::parse_file_to_list(input_file, |line| {
-        line.chars().collect()
+puzzle!(10, "Syntax Scoring");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<Vec<char>>, Error> {
+    helpers::parse_file_to_list(input, |line| {
+        if let Some(index) = line.find(|c| !"()[]{}<>".contains(c)) {
+            return Err(Error::parse(index + 1, format!("unknown character '{}'", line[index..].chars().next().unwrap())));
+        }
+
+        Ok(line.chars().collect())
     })
 }
 
-pub fn part1(input: &Vec<Vec<char>>) -> i32 {
+pub fn part1(input: &Vec<Vec<char>>) -> Result<i32, Error> {
     let mut score = 0;
     let mut chunks: Vec<char> = Vec::new();
     for line in input {
@@ -17,11 +23,7 @@ pub fn part1(input: &Vec<Vec<char>>) -> i32 {
         for c in line {
             match c {
                 '('|'['|'{'|'<' => chunks.push(c.clone()),
-                ')' => if chunks.pop() != Some('(') { corrupted_char = c.clone(); break;},
-                ']' => if chunks.pop() != Some('[') { corrupted_char = c.clone(); break;},
-                '}' => if chunks.pop() != Some('{') { corrupted_char = c.clone(); break;},
-                '>' => if chunks.pop() != Some('<') { corrupted_char = c.clone(); break;},
-                _ => println!("Unknown character '{}'", c),
+                _ => if chunks.pop() != Some(opening(*c)) { corrupted_char = *c; break; },
             }
         }
 
@@ -34,10 +36,10 @@ pub fn part1(input: &Vec<Vec<char>>) -> i32 {
         }
     }
 
-    score
+    Ok(score)
 }
 
-pub fn part2(input: &Vec<Vec<char>>) -> i64 {
+pub fn part2(input: &Vec<Vec<char>>) -> Result<i64, Error> {
     let mut scores: Vec<i64> = Vec::new();
     let mut chunks: Vec<char> = Vec::new();
     for line in input {
@@ -46,11 +48,7 @@ pub fn part2(input: &Vec<Vec<char>>) -> i64 {
         for c in line {
             match c {
                 '('|'['|'{'|'<' => chunks.push(c.clone()),
-                ')' => if chunks.pop() != Some('(') { corrupted = true; break; },
-                ']' => if chunks.pop() != Some('[') { corrupted = true; break; },
-                '}' => if chunks.pop() != Some('{') { corrupted = true; break; },
-                '>' => if chunks.pop() != Some('<') { corrupted = true; break; },
-                _ => println!("Unknown character '{}'", c),
+                _ => if chunks.pop() != Some(opening(*c)) { corrupted = true; break; },
             }
         }
 
@@ -73,7 +71,21 @@ pub fn part2(input: &Vec<Vec<char>>) -> i64 {
         scores.push(score);
     }
 
+    if scores.is_empty() {
+        return Err(Error::invalid_puzzle("no incomplete line"));
+    }
+
     scores.sort();
 
-    scores[scores.len() / 2]
+    Ok(scores[scores.len() / 2])
+}
+
+/// Opening bracket of a closing one, the parser only accepts brackets.
+fn opening(closing: char) -> char {
+    match closing {
+        ')' => '(',
+        ']' => '[',
+        '}' => '{',
+        _ => '<',
+    }
 }
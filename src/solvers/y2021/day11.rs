@@ -0,0 +1,89 @@
+use std::io::BufRead;
+use crate::deadline;
+use crate::error::Error;
+use crate::helpers::{self, FromChar};
+
+puzzle!(11, "Dumbo Octopus");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<helpers::Map<EnergyLevel>, Error> {
+    helpers::Map::parse_grid(input, |c| i32::from_char(c).map(EnergyLevel::Charge))
+}
+
+pub fn part1(input: &helpers::Map<EnergyLevel>) -> Result<i32, Error> {
+    let mut map = input.clone();
+    let mut score = 0;
+
+    for _ in 0..100 {
+        for pos in map.positions() {
+            score += map.increase_energy_level(pos);
+        }
+    
+        map.reset_energy_levels();
+    }
+
+    Ok(score)
+}
+
+pub fn part2(input: &helpers::Map<EnergyLevel>) -> Result<i32, Error> {
+    let mut map = input.clone();
+    let mut step = 1;
+    loop {
+        deadline::check()?;
+        let mut flashes = 0;
+        for pos in map.positions() {
+            flashes += map.increase_energy_level(pos);
+        }
+
+        if flashes == (map.width * map.height) as i32 {
+            break;
+        }
+    
+        map.reset_energy_levels();
+        step += 1;
+    }
+
+    Ok(step)
+}
+
+impl helpers::Map<EnergyLevel> {
+    fn increase_energy_level(&mut self, pos: helpers::Position) -> i32 {
+        if let Some(cell) = self.get_mut(pos) {
+            match *cell {
+                EnergyLevel::Flash => (),
+                EnergyLevel::Charge(energy) => {
+                    if energy >= 9 {
+                        *cell = EnergyLevel::Flash;
+                        let mut flashes = 1;
+                        for npos in pos.neighbours(true) {
+                            flashes += self.increase_energy_level(npos);
+                        }
+
+                        return flashes;
+                    } else {
+                        *cell = EnergyLevel::Charge(energy + 1);
+                    }
+                }
+            }
+        }
+
+        0
+    }
+
+    fn reset_energy_levels(&mut self) {
+        for cell in self.iter_mut() {
+            if let EnergyLevel::Flash = cell { *cell = EnergyLevel::Charge(0) }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum EnergyLevel {
+    Charge(i32),
+    Flash,
+}
+
+impl Default for EnergyLevel {
+    fn default() -> EnergyLevel {
+        EnergyLevel::Charge(0)
+    }
+}
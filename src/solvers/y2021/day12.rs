@@ -1,13 +1,17 @@
-use std::fs::File;
-use std::io;
-use std::fmt::{Display, Formatter, Result};
+use std::io::BufRead;
+use std::fmt::{self, Display, Formatter};
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Graph {
-    let inputs = helpers::parse_file_to_list(input_file, |line| {
-        let mut parts = line.split('-');
-        (String::from(parts.next().unwrap()), String::from(parts.next().unwrap()))
-    });
+puzzle!(12, "Passage Pathing");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Graph, Error> {
+    let inputs = helpers::parse_file_to_list(input, |line| {
+        match line.split_once('-') {
+            Some((start, end)) if !start.is_empty() && !end.is_empty() => Ok((String::from(start), String::from(end))),
+            _ => Err(Error::parse(1, "expected a connection 'start-end'")),
+        }
+    })?;
 
     let mut graph: Graph = Graph::new();
     
@@ -42,19 +46,19 @@ pub fn parser(input_file: io::BufReader<File>) -> Graph {
         }
     });
 
-    graph
+    Ok(graph)
 }
 
-pub fn part1(graph: &Graph) -> usize {
+pub fn part1(graph: &Graph) -> Result<usize, Error> {
     let mut path: Vec<usize> = Vec::new();
-    path.push(graph.caves.iter().position(|c| c.cave_type == Type::Start).unwrap());
-    graph.explore_path(path, false).len()
+    path.push(graph.start()?);
+    Ok(graph.explore_path(path, false).len())
 }
 
-pub fn part2(graph: &Graph) -> usize {
+pub fn part2(graph: &Graph) -> Result<usize, Error> {
     let mut path: Vec<usize> = Vec::new();
-    path.push(graph.caves.iter().position(|c| c.cave_type == Type::Start).unwrap());
-    graph.explore_path(path, true).len()
+    path.push(graph.start()?);
+    Ok(graph.explore_path(path, true).len())
 }
 
 pub struct Graph {
@@ -67,6 +71,10 @@ impl Graph {
             caves: Vec::new(),
         }
     }
+
+    fn start(&self) -> Result<usize, Error> {
+        self.caves.iter().position(|c| c.cave_type == Type::Start).ok_or_else(|| Error::invalid_puzzle("no start cave"))
+    }
     
     fn explore_path(&self, path: Vec<usize>, twice_for_one_small: bool) -> Vec<Vec<usize>> {
         let mut paths: Vec<Vec<usize>> = Vec::new();
@@ -153,7 +161,7 @@ impl Type {
 }
 
 impl Display for Type {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Type::Start => write!(f, "start"),
             Type::End => write!(f, "end"),
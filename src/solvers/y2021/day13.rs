@@ -0,0 +1,105 @@
+use std::io::BufRead;
+use regex::Regex;
+use crate::Answer;
+use crate::error::Error;
+use crate::helpers;
+use crate::ocr;
+
+puzzle!(13, "Transparent Origami");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<(helpers::Map<char>, Vec<FoldInstruction>), Error> {
+    let regex = Regex::new(r"^fold\salong\s([xy])=([0-9]+)$").unwrap();
+    let mut coordinates: Vec<helpers::Position> = Vec::new();
+    let mut instructions: Vec<FoldInstruction> = Vec::new();
+    let mut read_inst = false;
+    let mut width = 0usize;
+    let mut height = 0usize;
+    for (index, line) in input.lines().enumerate() {
+        let ip = line?;
+        if ip.is_empty() {
+            read_inst = true;
+            continue;
+        }
+
+        if read_inst {
+            let capture = helpers::parse_regex(&regex, &ip).map_err(|error| error.at_line(index + 1))?;
+            let orientation = &capture[1];
+            let line: i32 = helpers::parse_capture(&capture, 2).map_err(|error| error.at_line(index + 1))?;
+            instructions.push(FoldInstruction {
+                x: if orientation == "x" { line } else { 0 }, 
+                y: if orientation == "y" { line } else { 0 }, 
+            });
+        } else {
+            let (x, y) = ip.split_once(',').ok_or_else(|| Error::parse(1, "expected a dot 'x,y'").at_line(index + 1))?;
+            let position = helpers::Position::new(
+                helpers::parse_value::<u16>(x, 1).map_err(|error| error.at_line(index + 1))? as i32,
+                helpers::parse_value::<u16>(y, x.len() + 2).map_err(|error| error.at_line(index + 1))? as i32);
+            if position.x as usize >= width { width = position.x as usize + 1 }
+            if position.y as usize >= height { height = position.y as usize + 1 }
+            coordinates.push(position);
+        }
+    }
+
+    let mut map: helpers::Map<char> = helpers::Map::new_init(width, height, '.');
+    for c in coordinates {
+        map.set(c, '#');
+    }
+
+    Ok((map, instructions))
+}
+
+pub fn part1(input: &(helpers::Map<char>, Vec<FoldInstruction>)) -> Result<usize, Error> {
+    let map: &helpers::Map<char> = &input.0;
+    let instructions = &input.1;
+
+    let instruction = instructions.first().ok_or_else(|| Error::invalid_puzzle("no fold instruction"))?;
+    let folded_map = process_instruction(map, instruction)?;
+    Ok(folded_map.iter().fold(0, |count, c| if c == &'#' { count + 1 } else { count }))
+}
+
+pub fn part2(input: &(helpers::Map<char>, Vec<FoldInstruction>)) -> Result<Answer, Error> {
+    let map: &helpers::Map<char> = &input.0;
+    let instructions = &input.1;
+
+    let mut current_map = map.clone();
+    for instruction in instructions {
+        current_map = process_instruction(&current_map, instruction)?;
+    }
+    
+    // Fall back to the drawing when it can't be read
+    Ok(ocr::read_letters(&current_map).map_or_else(|| Answer::from(&current_map), Answer::Text))
+}
+
+fn process_instruction(map: &helpers::Map<char>, instruction: &FoldInstruction) -> Result<helpers::Map<char>, Error> {
+    if instruction.x as usize >= map.width || instruction.y as usize >= map.height {
+        return Err(Error::invalid_puzzle(format!("fold along x={} y={} is outside of the paper", instruction.x, instruction.y)));
+    }
+
+    if instruction.x > 0 {
+        Ok(fold_left(map, instruction.x as usize))
+    } else if instruction.y > 0 {
+        // Folding up is folding left along the diagonal
+        Ok(fold_left(&map.transpose(), instruction.y as usize).transpose())
+    } else {
+        Err(Error::invalid_puzzle("fold along line 0"))
+    }
+}
+
+fn fold_left(map: &helpers::Map<char>, fold: usize) -> helpers::Map<char> {
+    let mut fold_map = helpers::Map::new_init(map.width - fold, map.height, '.');
+    for (pos, c) in map.cells() {
+        if c != &'#' || pos.x as usize == fold {
+            continue;
+        }
+
+        let x = if (pos.x as usize) < fold { pos.x } else { 2 * fold as i32 - pos.x };
+        fold_map.set(helpers::Position::new(x, pos.y), '#');
+    }
+
+    fold_map
+}
+
+pub struct FoldInstruction {
+    x: i32,
+    y: i32,
+}
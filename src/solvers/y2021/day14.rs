@@ -1,40 +1,43 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::BufRead;
 use std::collections::HashMap;
 use regex::Regex;
+use crate::error::Error;
+use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> (String, Vec<InsertionRule>) {
-    let regex = Regex::new(r"^([A-Z]+)\s->\s([A-Z])$").unwrap();
+puzzle!(14, "Extended Polymerization");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<(String, Vec<InsertionRule>), Error> {
+    let regex = Regex::new(r"^([A-Z])([A-Z])\s->\s([A-Z])$").unwrap();
     let mut instructions: Vec<InsertionRule> = Vec::new();
     let mut template: String = String::new();
     let mut template_read = false;
-    for line in input_file.lines() {
-        if let Ok(ip) = line {
-            if ip == "" {
-                template_read = true;
-                continue;
-            }
+    for (index, line) in input.lines().enumerate() {
+        let ip = line?;
+        if ip.is_empty() {
+            template_read = true;
+            continue;
+        }
 
-            if template_read {
-                if let Option::Some(capture) = regex.captures(&ip) {
-                    let mut from = capture.get(1).unwrap().as_str().chars();
-                    let mut to = capture.get(2).unwrap().as_str().chars();
-                    instructions.push(InsertionRule {
-                        start: from.next().unwrap(),
-                        end: from.next().unwrap(),
-                        result: to.next().unwrap(),
-                    });
-                }
-            } else {
-                template = String::from(ip);
-            }
+        if template_read {
+            let capture = helpers::parse_regex(&regex, &ip).map_err(|error| error.at_line(index + 1))?;
+            instructions.push(InsertionRule {
+                start: helpers::parse_capture(&capture, 1)?,
+                end: helpers::parse_capture(&capture, 2)?,
+                result: helpers::parse_capture(&capture, 3)?,
+            });
+        } else {
+            template = ip;
         }
     }
 
-    (template, instructions)
+    if template.is_empty() {
+        return Err(Error::invalid_puzzle("no polymer template"));
+    }
+
+    Ok((template, instructions))
 }
 
-pub fn part1(input: &(String, Vec<InsertionRule>)) -> i32 {
+pub fn part1(input: &(String, Vec<InsertionRule>)) -> Result<i32, Error> {
     let mut template = input.0.clone();
     let rules = &input.1;
     
@@ -73,10 +76,10 @@ pub fn part1(input: &(String, Vec<InsertionRule>)) -> i32 {
         if count < min { min = count }
     }
 
-    max - min
+    Ok(max - min)
 }
 
-pub fn part2(input: &(String, Vec<InsertionRule>)) -> u64 {
+pub fn part2(input: &(String, Vec<InsertionRule>)) -> Result<u64, Error> {
     let template = input.0.clone();
     let rules = &input.1;
 
@@ -133,7 +136,7 @@ pub fn part2(input: &(String, Vec<InsertionRule>)) -> u64 {
         if real_count < min { min = real_count }
     }
 
-    max - min
+    Ok(max - min)
 }
 
 pub struct InsertionRule {
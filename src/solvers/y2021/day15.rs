@@ -0,0 +1,48 @@
+use std::io::BufRead;
+use crate::error::Error;
+use crate::helpers::{self, search, FromChar};
+
+puzzle!(15, "Chiton");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<helpers::Map<i32>, Error> {
+    helpers::Map::parse_grid(input, i32::from_char)
+}
+
+pub fn part1(input: &helpers::Map<i32>) -> Result<i32, Error> {
+    lowest_risk(input)
+}
+
+pub fn part2(input: &helpers::Map<i32>) -> Result<i32, Error> {
+    let mut map: helpers::Map<i32> = helpers::Map::new(input.width * 5, input.height * 5);
+
+    for (pos, &risk) in input.cells() {
+        for x_offset in 0..5i32 {
+            for y_offset in 0..5i32 {
+                let offset_risk = (risk - 1 + x_offset + y_offset) % 9 + 1;
+                let position = helpers::Position::new(pos.x + x_offset * input.width as i32, pos.y + y_offset * input.height as i32);
+                map.set(position, offset_risk);
+            }
+        }
+    }
+
+    lowest_risk(&map)
+}
+
+fn heuristic(start: &helpers::Position, destination: &helpers::Position) -> i32 {
+    (destination.x - start.x).abs() + (destination.y - start.y).abs()
+}
+
+/// Risk of the safest path from the top left to the bottom right corner, entering a cell costs its risk.
+fn lowest_risk(map: &helpers::Map<i32>) -> Result<i32, Error> {
+    if map.is_empty() {
+        return Err(Error::invalid_puzzle("empty cave"));
+    }
+
+    let destination = helpers::Position::new((map.width - 1) as i32, (map.height - 1) as i32);
+    search::astar(helpers::Position::new(0, 0),
+        |&pos| map.neighbours(pos, false).map(|(neighbour, &risk)| (neighbour, risk)),
+        |pos| heuristic(pos, &destination),
+        |&pos| pos == destination)
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::invalid_puzzle("no path to the bottom right corner"))
+}
@@ -1,33 +1,47 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-
-pub fn parser(input_file: io::BufReader<File>) -> String {
-    for line in input_file.lines() {
-        if let Ok(ip) = line {
-            let mut input = String::new();
-            for c in ip.chars() {
-                let value = u8::from_str_radix(&c.to_string(), 16).unwrap();
-                let string_value = format!("{:04b}", value);
-                input.push_str(string_value.as_str())
-            }
-
-            return input;
+use std::io::BufRead;
+use crate::error::Error;
+
+puzzle!(16, "Packet Decoder");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<String, Error> {
+    if let Some(line) = input.lines().next() {
+        let ip = line?;
+        let mut bits = String::new();
+        for (i, c) in ip.chars().enumerate() {
+            let value = c.to_digit(16).ok_or_else(|| Error::parse(i + 1, format!("expected an hexadecimal digit, found '{}'", c)).at_line(1))?;
+            let string_value = format!("{:04b}", value);
+            bits.push_str(string_value.as_str())
         }
+
+        return Ok(bits);
     }
 
-    panic!("No input found")
+    Err(Error::invalid_puzzle("no input found"))
 }
 
-pub fn part1(input: &String) -> i32 {
+pub fn part1(input: &String) -> Result<i32, Error> {
     let data = input.as_str();
-    let (packet, _, _) = Packet::parse(data);
-    count_version(&packet)
+    let (packet, _, _) = Packet::parse(data, data.len())?;
+    Ok(count_version(&packet))
 }
 
-pub fn part2(input: &String) -> i64 {
+pub fn part2(input: &String) -> Result<i64, Error> {
     let data = input.as_str();
-    let (packet, _, _) = Packet::parse(data);
-    packet.evaluate()
+    let (packet, _, _) = Packet::parse(data, data.len())?;
+    Ok(packet.evaluate())
+}
+
+fn split_data(data: &str, length: usize) -> Result<(&str, &str), Error> {
+    if data.len() < length {
+        return Err(Error::invalid_puzzle("truncated packet"));
+    }
+
+    Ok(data.split_at(length))
+}
+
+/// Parse error at the hexadecimal digit of the transmission including the start of `data`.
+fn packet_error(data: &str, total_length: usize, message: &str) -> Error {
+    Error::parse((total_length - data.len()) / 4 + 1, message).at_line(1)
 }
 
 fn count_version(packet: &Packet) -> i32 {
@@ -51,17 +65,17 @@ enum Type {
 }
 
 impl Type {
-    fn from(type_id:u8) -> Self {
+    fn from(type_id: u8) -> Option<Self> {
         match type_id {
-            0 => Type::Sum,
-            1 => Type::Product,
-            2 => Type::Minimum,
-            3 => Type::Maximum,
-            4 => Type::Literal,
-            5 => Type::GreaterThan,
-            6 => Type::LessThan,
-            7 => Type::Equal,
-            _ => panic!("Unknown type id {}", type_id),
+            0 => Some(Type::Sum),
+            1 => Some(Type::Product),
+            2 => Some(Type::Minimum),
+            3 => Some(Type::Maximum),
+            4 => Some(Type::Literal),
+            5 => Some(Type::GreaterThan),
+            6 => Some(Type::LessThan),
+            7 => Some(Type::Equal),
+            _ => None,
         }
     }
 }
@@ -79,13 +93,16 @@ struct Packet {
 }
 
 impl Packet {
-    fn parse(data: &str) -> (Packet, &str, usize) {
+    /// Parse the packet at the start of `data`, the remaining bits of a transmission of `total_length` bits.
+    fn parse(data: &str, total_length: usize) -> Result<(Packet, &str, usize), Error> {
+        let start = data;
         let mut data = data;
-        let (string, next_data) = data.split_at(3);
+        let (string, next_data) = split_data(data, 3)?;
         let version = u8::from_str_radix(string, 2).unwrap();
         data = next_data;
-        let (string, next_data) = data.split_at(3);
-        let type_id = Type::from(u8::from_str_radix(string, 2).unwrap());
+        let (string, next_data) = split_data(data, 3)?;
+        let type_id = Type::from(u8::from_str_radix(string, 2).unwrap())
+            .ok_or_else(|| packet_error(start, total_length, "unknown packet type id"))?;
         data = next_data;
 
         let mut packet_length = 6;
@@ -95,11 +112,11 @@ impl Packet {
                 // Literal packet
                 let mut content_string = String::new();
                 loop {
-                    let (string, next_data) = data.split_at(1);
+                    let (string, next_data) = split_data(data, 1)?;
                     let last = string.chars().next().unwrap() == '0';
                     data = next_data;
         
-                    let (string, next_data) = data.split_at(4);
+                    let (string, next_data) = split_data(data, 4)?;
                     content_string.push_str(string);
                     data = next_data;
         
@@ -109,7 +126,7 @@ impl Packet {
                     }
                 }
         
-                let content = i64::from_str_radix(content_string.as_str(), 2).unwrap();
+                let content = i64::from_str_radix(content_string.as_str(), 2).map_err(|_| Error::invalid_puzzle("literal value does not fit in 64 bits"))?;
 
                 packet = Packet {
                     version: version,
@@ -121,33 +138,33 @@ impl Packet {
             }
             _ => {
                 // Operator packet
-                let (string, next_data) = data.split_at(1);
+                let (string, next_data) = split_data(data, 1)?;
                 let length_type_id = string.chars().next().unwrap() == '1';
                 data = next_data;
                 packet_length += 1;
 
                 let mut sub_packets: Vec<Packet> = Vec::new();
                 if length_type_id {
-                    let (string, next_data) = data.split_at(11);
+                    let (string, next_data) = split_data(data, 11)?;
                     let subpacket_count = i32::from_str_radix(string, 2).unwrap();
                     data = next_data;
                     packet_length += 11;
 
                     for _ in 0..subpacket_count {
-                        let (sub_packet, next_data, subpacket_length) = Packet::parse(data);
+                        let (sub_packet, next_data, subpacket_length) = Packet::parse(data, total_length)?;
                         sub_packets.push(sub_packet);
                         data = next_data;
                         packet_length += subpacket_length;
                     }
                 } else {
-                    let (string, next_data) = data.split_at(15);
-                    let total_length = usize::from_str_radix(string, 2).unwrap();
+                    let (string, next_data) = split_data(data, 15)?;
+                    let sub_packets_length_limit = usize::from_str_radix(string, 2).unwrap();
                     data = next_data;
                     packet_length += 15;
 
                     let mut sub_packets_length = 0;
-                    while sub_packets_length < total_length {
-                        let (sub_packet, next_data, subpacket_length) = Packet::parse(data);
+                    while sub_packets_length < sub_packets_length_limit {
+                        let (sub_packet, next_data, subpacket_length) = Packet::parse(data, total_length)?;
                         sub_packets.push(sub_packet);
                         data = next_data;
                         sub_packets_length += subpacket_length;
@@ -156,6 +173,16 @@ impl Packet {
                     packet_length += sub_packets_length;
                 }
 
+                match type_id {
+                    Type::GreaterThan | Type::LessThan | Type::Equal if sub_packets.len() != 2 => {
+                        return Err(packet_error(start, total_length, "comparison packets must have 2 sub-packets"));
+                    },
+                    _ if sub_packets.is_empty() => {
+                        return Err(packet_error(start, total_length, "operator packets must have sub-packets"));
+                    },
+                    _ => (),
+                }
+
                 packet = Packet {
                     version: version,
                     type_id: type_id,
@@ -166,7 +193,7 @@ impl Packet {
             }
         }
 
-        return (packet, data, packet_length);
+        return Ok((packet, data, packet_length));
     }
 
     fn evaluate(&self) -> i64 {
@@ -1,44 +1,39 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::BufRead;
 use regex::Regex;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> TargetZone {
+puzzle!(17, "Trick Shot");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<TargetZone, Error> {
     let regex = Regex::new(r"^target\sarea: x=(-?[0-9]+)\.\.(-?[0-9]+),\sy=(-?[0-9]+)\.\.(-?[0-9]+)$").unwrap();
-    for line in input_file.lines() {
-        if let Ok(ip) = line {
-            if let Option::Some(capture) = regex.captures(&ip) {
-                let x_min = capture.get(1).unwrap().as_str().parse::<i32>().unwrap();
-                let x_max = capture.get(2).unwrap().as_str().parse::<i32>().unwrap();
-                let y_min = capture.get(3).unwrap().as_str().parse::<i32>().unwrap();
-                let y_max = capture.get(4).unwrap().as_str().parse::<i32>().unwrap();
-                return TargetZone::new(x_min, x_max, y_min, y_max)
-            }
+    for (index, line) in input.lines().enumerate() {
+        let ip = line?;
+        if let Option::Some(capture) = regex.captures(&ip) {
+            let parse = |i| helpers::parse_capture::<i32>(&capture, i).map_err(|error| error.at_line(index + 1));
+            return Ok(TargetZone::new(parse(1)?, parse(2)?, parse(3)?, parse(4)?))
         }
     }
 
-    panic!("No input found")
+    Err(Error::invalid_puzzle("no target area found"))
 }
 
-pub fn part1(input: &TargetZone) -> i32 {
+pub fn part1(input: &TargetZone) -> Result<i32, Error> {
     let mut y_max = 0;
-    let mut v_max = helpers::Position::default();
     for vx in 1..input.x_max+1 {
         for vy in input.y_min..100 {
             let start_velocity = helpers::Position::new(vx, vy);
             let (hit_target, max_y) = evoluate(input, &start_velocity);
             if hit_target && max_y > y_max {
                 y_max = max_y;
-                v_max = start_velocity;
             }
         }
     }
 
-    println!("velocity: {}", v_max);
-    y_max
+    Ok(y_max)
 }
 
-pub fn part2(input: &TargetZone) -> i32 {
+pub fn part2(input: &TargetZone) -> Result<i32, Error> {
     let mut count = 0;
     for vx in 1..input.x_max+1 {
         for vy in input.y_min..100 {
@@ -50,7 +45,7 @@ pub fn part2(input: &TargetZone) -> i32 {
         }
     }
 
-    count
+    Ok(count)
 }
 
 fn evoluate(target: &TargetZone, start_velocity: &helpers::Position) -> (bool, i32) {
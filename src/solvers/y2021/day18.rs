@@ -1,22 +1,28 @@
-use std::fs::File;
 use std::ops::{Add, AddAssign};
-use std::fmt::{Display, Formatter, Result};
-use std::io;
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<Number> {
-    let inputs: Vec<Number> = helpers::parse_file_to_list(input_file, |line| {
+puzzle!(18, "Snailfish");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<Number>, Error> {
+    let inputs: Vec<Number> = helpers::parse_file_to_list(input, |line| {
         let mut pairs: Vec<Pair> = Vec::new();
         let mut open_elements: Vec<usize> = Vec::new();
         let mut root_index: usize = usize::MAX;
-        for c in line.chars() {
+        for (i, c) in line.chars().enumerate() {
+            if root_index != usize::MAX {
+                return Err(Error::parse(i + 1, "unexpected character after the end of the number"));
+            }
+
             match c {
                 '[' => {
                     pairs.push(Pair::default());
                     open_elements.push(pairs.len() - 1);
                 },
                 '0'..='9' => {
-                    let &pair_index = open_elements.last().unwrap();
+                    let &pair_index = open_elements.last().ok_or_else(|| Error::parse(i + 1, "value outside of a pair"))?;
                     let mut pair = &mut pairs[pair_index];
                     if pair.left == Element::None {
                         pair.left = Element::Value(u16::from_str_radix(&c.to_string(), 10).unwrap());
@@ -26,7 +32,11 @@ pub fn parser(input_file: io::BufReader<File>) -> Vec<Number> {
                 }
                 ',' => (),
                 ']' => {
-                    let finished_pair_index = open_elements.pop().unwrap();
+                    let finished_pair_index = open_elements.pop().ok_or_else(|| Error::parse(i + 1, "unbalanced ']'"))?;
+                    if pairs[finished_pair_index].right == Element::None {
+                        return Err(Error::parse(i + 1, "pair is missing an element"));
+                    }
+
                     if open_elements.len() == 0 {
                         root_index = finished_pair_index;
                     } else {
@@ -39,44 +49,45 @@ pub fn parser(input_file: io::BufReader<File>) -> Vec<Number> {
                         }
                     }
                 },
-                _ => panic!("Unknown character"),
+                _ => return Err(Error::parse(i + 1, format!("unknown character '{}'", c))),
             }
         }
 
-        assert!(open_elements.len() == 0);
-        assert!(root_index != usize::MAX);
-        Number::new(pairs, root_index)
-    });
+        if root_index == usize::MAX {
+            return Err(Error::parse(line.len() + 1, "unterminated number"));
+        }
+
+        Ok(Number::new(pairs, root_index))
+    })?;
 
-    inputs
+    Ok(inputs)
 }
 
-pub fn part1(input: &Vec<Number>) -> i32 {
-    let mut number = input[0].clone();
+pub fn part1(input: &Vec<Number>) -> Result<i32, Error> {
+    let mut number = input.first().ok_or_else(|| Error::invalid_puzzle("no snailfish number"))?.clone();
 
     for i in 1..input.len() {
         number += &input[i];
-        number.reduce(false);
+        number.reduce();
     }
     
-    println!("{}", number);
-    number.magnitude()
+    Ok(number.magnitude())
 }
 
-pub fn part2(input: &Vec<Number>) -> i32 {
+pub fn part2(input: &Vec<Number>) -> Result<i32, Error> {
     let mut max_magnitude = 0;
     for i1 in 0..input.len() {
         for i2 in 0..input.len() {
             let mut n1 = input[i1].clone();
             n1 += &input[i2];
-            n1.reduce(false);
+            n1.reduce();
             if n1.magnitude() > max_magnitude {
                 max_magnitude = n1.magnitude();
             }
         }
     }
 
-    max_magnitude
+    Ok(max_magnitude)
 }
 
 #[derive(PartialEq)]
@@ -153,13 +164,9 @@ impl Number {
         }
     }
 
-    pub fn reduce(&mut self, verbose: bool) {
+    pub fn reduce(&mut self) {
         self.refresh_parents();
         loop {
-            if verbose {
-                println!("  {}", self);
-            }
-
             if self.explode_pair(self.root, 0) {
                 // self.refresh_parents();
                 continue;
@@ -310,7 +317,7 @@ impl Number {
         3 * left + 2 * right
     }
     
-    fn fmt_pair(&self, pair: &Pair, f: &mut Formatter<'_>) -> Result {
+    fn fmt_pair(&self, pair: &Pair, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "[")?;
         self.fmt_element(pair.left, f)?;
         write!(f, ",")?;
@@ -319,7 +326,7 @@ impl Number {
         Ok(())
     }
 
-    fn fmt_element(&self, element: Element, f: &mut Formatter<'_>) -> Result {
+    fn fmt_element(&self, element: Element, f: &mut Formatter<'_>) -> fmt::Result {
         match element {
             Element::None => write!(f, "None"),
             Element::Value(value) => write!(f, "{}", value),
@@ -377,7 +384,7 @@ impl AddAssign<&Self> for Number {
 
 impl Display for Number
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.fmt_pair(&self.pairs[self.root], f)
     }
 }
@@ -1,53 +1,70 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::BufRead;
 use regex::Regex;
 use ndarray::{Array1, arr1, Array2, arr2};
+use crate::deadline;
+use crate::error::Error;
+use crate::helpers;
+
+puzzle!(19, "Beacon Scanner");
 
 const MATCH_COUNT: usize = 12;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<ScannerInput> {
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<ScannerInput>, Error> {
     let scanner_regex = Regex::new(r"^--- scanner ([0-9]+) ---$").unwrap();
     let beacon_regex = Regex::new(r"^(-?[0-9]+),(-?[0-9]+),(-?[0-9]+)$").unwrap();
     let mut scanner = ScannerInput::default();
 
     let mut inputs: Vec<ScannerInput> = Vec::new();
-    for line in input_file.lines() {
-        if let Ok(ip) = line {
-            if let Option::Some(capture) = scanner_regex.captures(&ip) {
-                assert!(scanner.id == u16::MAX);
-                scanner.id = capture.get(1).unwrap().as_str().parse::<u16>().unwrap();
-                continue;
+    for (index, line) in input.lines().enumerate() {
+        let ip = line?;
+        if let Option::Some(capture) = scanner_regex.captures(&ip) {
+            if scanner.id != u16::MAX {
+                return Err(Error::parse(1, "missing empty line before the scanner header").at_line(index + 1));
             }
 
-            if let Option::Some(capture) = beacon_regex.captures(&ip) {
-                assert!(scanner.id != u16::MAX);
-                let x = capture.get(1).unwrap().as_str().parse::<i32>().unwrap();
-                let y = capture.get(2).unwrap().as_str().parse::<i32>().unwrap();
-                let z = capture.get(3).unwrap().as_str().parse::<i32>().unwrap();
-                scanner.beacons.push(arr1(&[x, y, z]));
-                continue;
+            scanner.id = helpers::parse_capture(&capture, 1).map_err(|error| error.at_line(index + 1))?;
+            continue;
+        }
+
+        if let Option::Some(capture) = beacon_regex.captures(&ip) {
+            if scanner.id == u16::MAX {
+                return Err(Error::parse(1, "beacon outside of a scanner report").at_line(index + 1));
             }
 
-            if ip == "" {
+            let parse = |i| helpers::parse_capture::<i32>(&capture, i).map_err(|error| error.at_line(index + 1));
+            scanner.beacons.push(arr1(&[parse(1)?, parse(2)?, parse(3)?]));
+            continue;
+        }
+
+        if ip.is_empty() {
+            // Extra empty lines do not start another scanner report
+            if scanner.id != u16::MAX {
                 inputs.push(scanner);
                 scanner = ScannerInput::default();
-                continue;
             }
+
+            continue;
         }
+
+        return Err(Error::parse(1, format!("unexpected line '{}'", ip)).at_line(index + 1));
     }
-    
-    inputs.push(scanner);
 
-    inputs
+    if scanner.id != u16::MAX {
+        inputs.push(scanner);
+    }
+
+    if let Some(scanner) = inputs.iter().find(|scanner| scanner.beacons.len() < MATCH_COUNT) {
+        return Err(Error::invalid_puzzle(format!("scanner {} sees less than {} beacons", scanner.id, MATCH_COUNT)));
+    }
+
+    Ok(inputs)
 }
 
-pub fn part1(inputs: &Vec<ScannerInput>) -> usize {
-    let scanners = analyse_scanner(inputs);
+pub fn part1(inputs: &Vec<ScannerInput>) -> Result<usize, Error> {
+    let scanners = analyse_scanner(inputs)?;
 
     let mut beacons: Vec<Array1<i32>> = Vec::new();
     for scanner in &scanners {
-        assert!(scanner.defined);
-
         for beacon in &scanner.input.beacons {
             let ref_pos = scanner.orientation.dot(beacon) + &scanner.position;
             if !beacons.contains(&ref_pos) {
@@ -56,17 +73,15 @@ pub fn part1(inputs: &Vec<ScannerInput>) -> usize {
         }
     }
 
-    beacons.len()
+    Ok(beacons.len())
 }
 
-pub fn part2(inputs: &Vec<ScannerInput>) -> i32 {
-    let scanners = analyse_scanner(inputs);
+pub fn part2(inputs: &Vec<ScannerInput>) -> Result<i32, Error> {
+    let scanners = analyse_scanner(inputs)?;
 
     let mut max_distance = 0;
     for scanner1 in &scanners {
-        assert!(scanner1.defined);
         for scanner2 in &scanners {
-            assert!(scanner2.defined);
             let diff = &scanner1.position - &scanner2.position;
             let distance = diff[0].abs() + diff[1].abs() + diff[2].abs();
             if distance > max_distance {
@@ -75,10 +90,10 @@ pub fn part2(inputs: &Vec<ScannerInput>) -> i32 {
         }
     }
     
-    max_distance
+    Ok(max_distance)
 }
 
-fn analyse_scanner<'a>(inputs: &'a Vec<ScannerInput>) -> Vec<Scanner<'a>> {
+fn analyse_scanner<'a>(inputs: &'a Vec<ScannerInput>) -> Result<Vec<Scanner<'a>>, Error> {
     let mut scanners: Vec<Scanner> = Vec::new();
 
     for input in inputs {
@@ -94,8 +109,9 @@ fn analyse_scanner<'a>(inputs: &'a Vec<ScannerInput>) -> Vec<Scanner<'a>> {
 
     let rotation_matrixes = generate_rotation_matrixes();
 
-    let mut scanners_defined = 1;
+    let mut scanners_defined = scanners.iter().filter(|scanner| scanner.defined).count();
     loop {
+        let previously_defined = scanners_defined;
         for reference_index in 0..scanners.len() {
             for scanner_index in 1..scanners.len() {
                 if reference_index == scanner_index {
@@ -106,13 +122,13 @@ fn analyse_scanner<'a>(inputs: &'a Vec<ScannerInput>) -> Vec<Scanner<'a>> {
                     continue;
                 }
     
+                deadline::check()?;
                 let (has_matches, position, orientation) = scanners[scanner_index].find_orientation_and_offset(&rotation_matrixes, &scanners[reference_index]);
                 if has_matches {
                     scanners[scanner_index].position = &scanners[reference_index].position + scanners[reference_index].orientation.dot(&position);
                     scanners[scanner_index].orientation = scanners[reference_index].orientation.dot(&orientation);
                     scanners[scanner_index].defined = true;
                     scanners_defined += 1;
-                    println!("{}%", scanners_defined * 100 / scanners.len());
                 }
             }
         }
@@ -120,11 +136,17 @@ fn analyse_scanner<'a>(inputs: &'a Vec<ScannerInput>) -> Vec<Scanner<'a>> {
         if scanners_defined >= scanners.len() {
             break;
         }
+
+        if scanners_defined == previously_defined {
+            return Err(Error::invalid_puzzle(format!("{} scanners cannot be located", scanners.len() - scanners_defined)));
+        }
     }
 
-    assert!(scanners_defined == scanners.iter().fold(0, |sum, scanner| sum + if scanner.defined { 1 } else { 0 }));
+    if let Some(scanner) = scanners.iter().find(|scanner| !scanner.defined) {
+        return Err(Error::invalid_puzzle(format!("scanner {} cannot be located", scanner.input.id)));
+    }
 
-    scanners
+    Ok(scanners)
 }
 
 fn generate_rotation_matrixes() -> Vec<Array2<i32>> {
@@ -216,18 +238,18 @@ impl<'a> Scanner<'a> {
                     for ref_end_beacon_index in ref_start_beacon_index+1..reference.input.beacons.len() {
                         let ref_distance = &reference.input.beacons[ref_end_beacon_index] - &reference.input.beacons[ref_start_beacon_index];
                         if distance == ref_distance {
-                            let offset;
                             let start_start_offset = &reference.input.beacons[ref_start_beacon_index] - start_beacon;
                             let end_end_offset = &reference.input.beacons[ref_end_beacon_index] - end_beacon;
                             let start_end_offset = &reference.input.beacons[ref_start_beacon_index] - end_beacon;
                             let end_start_offset = &reference.input.beacons[ref_end_beacon_index] - start_beacon;
-                            if start_start_offset == end_end_offset {
-                                offset = start_start_offset;
+                            let offset = if start_start_offset == end_end_offset {
+                                start_start_offset
                             } else if start_end_offset == end_start_offset {
-                                offset = start_end_offset;
+                                start_end_offset
                             } else {
-                                panic!("should have found an offset"); 
-                            }
+                                // The two pairs of beacons don't line up
+                                continue;
+                            };
 
                             if self.has_matches(orientation, &offset, reference) {
                                 return (true, offset);
@@ -0,0 +1,71 @@
+use std::io::BufRead;
+use crate::error::Error;
+use crate::helpers::{Boundary, Map, Position};
+
+puzzle!(20, "Trench Map");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<(String, Map<char>), Error> {
+    let pixel = |c| match c {
+        '#' | '.' => Ok(c),
+        _ => Err(String::from("expected '#' or '.'")),
+    };
+
+    let mut enhancement = String::new();
+    input.read_line(&mut enhancement)?;
+    let enhancement = enhancement.trim_end().to_string();
+    for (index, c) in enhancement.chars().enumerate() {
+        pixel(c).map_err(|message| Error::parse(index + 1, message).at_line(1))?;
+    }
+    if enhancement.len() != 512 {
+        return Err(Error::parse(1, "enhancement algorithm must be 512 characters long").at_line(1));
+    }
+
+    let image = Map::parse_grid(input, pixel).map_err(|error| error.after_lines(1))?;
+    Ok((enhancement, image))
+}
+
+pub fn part1(input: &(String, Map<char>)) -> Result<i32, Error> {
+    Ok(count_lit(&enhance_times(input, 2)))
+}
+
+pub fn part2(input: &(String, Map<char>)) -> Result<i32, Error> {
+    Ok(count_lit(&enhance_times(input, 50)))
+}
+
+fn count_lit(image: &Map<char>) -> i32 {
+    image.iter().fold(0, |count, &cell| count + if cell == '#' { 1 } else { 0 })
+}
+
+fn enhance_times(input: &(String, Map<char>), times: usize) -> Map<char> {
+    let enhancement = &input.0;
+    // The image is infinite, the pixels outside of the input are all dark
+    let mut image = input.1.clone().with_boundary(Boundary::Constant('.'));
+    for _ in 0..times {
+        image = enhance(&image, enhancement);
+    }
+
+    image
+}
+
+fn enhance(image: &Map<char>, enhancement: &str) -> Map<char> {
+    let offset = 2i32;
+    let output = Map::from_fn(image.width + offset as usize, image.height + offset as usize, |pos| {
+        let mut value = 0;
+        for i in 0..9 {
+            let ix = pos.x - offset/2 + i % 3 - 1;
+            let iy = pos.y - offset/2 + i / 3 - 1;
+            if image.get(Position::new(ix, iy)) == Some(&'#') {
+                value += 1 << (8 - i);
+            }
+        }
+
+        enhancement.chars().nth(value).unwrap()
+    });
+
+    // The infinite pixels outside of the image are all enhanced from 9 pixels alike
+    let outside = match image.boundary() {
+        Boundary::Constant('#') => enhancement.chars().nth(511).unwrap(),
+        _ => enhancement.chars().next().unwrap(),
+    };
+    output.with_boundary(Boundary::Constant(outside))
+}
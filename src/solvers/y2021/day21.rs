@@ -1,29 +1,37 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::BufRead;
 use regex::Regex;
+use crate::error::Error;
+use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> (u8, u8) {
+puzzle!(21, "Dirac Dice");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<(u8, u8), Error> {
     let regex = Regex::new(r"^Player ([12]) starting position: ([0-9]+)$").unwrap();
     let mut player1_start = u8::MAX;
     let mut player2_start = u8::MAX;
-    for line in input_file.lines() {
-        if let Ok(ip) = line {
-            if let Option::Some(capture) = regex.captures(&ip) {
-                let player = capture.get(1).unwrap().as_str().parse::<u8>().unwrap();
-                let start = capture.get(2).unwrap().as_str().parse::<u8>().unwrap();
-                match player {
-                    1 => player1_start = start,
-                    2 => player2_start = start,
-                    _ => panic!("Unknown player"),
-                }
-            }
+    for (index, line) in input.lines().enumerate() {
+        let ip = line?;
+        let capture = helpers::parse_regex(&regex, &ip).map_err(|error| error.at_line(index + 1))?;
+        let player = &capture[1];
+        let start = helpers::parse_capture::<u8>(&capture, 2).map_err(|error| error.at_line(index + 1))?;
+        if !(1..=10).contains(&start) {
+            return Err(Error::parse(capture.get(2).unwrap().start() + 1, "starting position must be between 1 and 10").at_line(index + 1));
+        }
+
+        match player {
+            "1" => player1_start = start,
+            _ => player2_start = start,
         }
     }
 
-    (player1_start, player2_start)
+    if player1_start == u8::MAX || player2_start == u8::MAX {
+        return Err(Error::invalid_puzzle("missing player starting position"));
+    }
+
+    Ok((player1_start, player2_start))
 }
 
-pub fn part1(input: &(u8, u8)) -> u32 {
+pub fn part1(input: &(u8, u8)) -> Result<u32, Error> {
     let mut players = [PlayerState { position: input.0 as u16, score: 0}, PlayerState { position: input.1 as u16, score: 0}];
     let mut current_player = 0;
     let mut dice = 0;
@@ -39,16 +47,12 @@ pub fn part1(input: &(u8, u8)) -> u32 {
         current_player = if current_player == 0 { 1 } else { 0 };
     }
 
-    println!("Dice: {} ", dice);
-    println!("Player 1 @ {} score: {}", players[0].position, players[0].score);
-    println!("Player 2 @ {} score: {}", players[1].position, players[1].score);
-
     let smallest = if players[0].score > players[1].score { players[1].score } else { players[0].score };
 
-    smallest * dice as u32
+    Ok(smallest * dice as u32)
 }
 
-pub fn part2(input: &(u8, u8)) -> u64 {
+pub fn part2(input: &(u8, u8)) -> Result<u64, Error> {
     let game_state = GameState {
         players: [PlayerState { position: input.0 as u16, score: 0}, PlayerState { position: input.1 as u16, score: 0}],
         current_player: 0,
@@ -70,7 +74,7 @@ pub fn part2(input: &(u8, u8)) -> u64 {
         wins.1 += branch.1 * weight[dices];
     }
     
-    if wins.0 > wins.1 { wins.0 } else { wins.1 }
+    Ok(if wins.0 > wins.1 { wins.0 } else { wins.1 })
 }
 
 fn play_turn(mut game_state: GameState, dice_value: usize, weight: &[u64]) -> (u64, u64) {
@@ -1,41 +1,40 @@
-use std::fs::File;
-use std::io;
+use std::io::BufRead;
 use std::collections::HashMap;
 use regex::Regex;
-use crate::helpers::parse_file_to_list;
+use crate::error::Error;
+use crate::helpers::{parse_capture, parse_file_to_list, parse_regex};
+
+puzzle!(22, "Reactor Reboot");
 
 const SIZE: usize = 101;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<Instruction> {
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<Instruction>, Error> {
     let regex = Regex::new(r"^(on|off) x=(-?[0-9]+)..(-?[0-9]+),y=(-?[0-9]+)..(-?[0-9]+),z=(-?[0-9]+)..(-?[0-9]+)$").unwrap();
-    let inputs: Vec<Instruction> = parse_file_to_list(input_file, |line| {
-        if let Option::Some(capture) = regex.captures(line) {
-            Instruction {
-                zone: Zone {
-                    min: [capture.get(2).unwrap().as_str().parse::<i32>().unwrap(), capture.get(4).unwrap().as_str().parse::<i32>().unwrap(), capture.get(6).unwrap().as_str().parse::<i32>().unwrap()],
-                    max: [capture.get(3).unwrap().as_str().parse::<i32>().unwrap(), capture.get(5).unwrap().as_str().parse::<i32>().unwrap(), capture.get(7).unwrap().as_str().parse::<i32>().unwrap()],
-                },
-                state: capture.get(1).unwrap().as_str() == "on",
-            }
-        } else {
-            panic!("");
-        }
-    });
-
-    inputs
+    let inputs: Vec<Instruction> = parse_file_to_list(input, |line| {
+        let capture = parse_regex(&regex, line)?;
+        Ok(Instruction {
+            zone: Zone {
+                min: [parse_capture(&capture, 2)?, parse_capture(&capture, 4)?, parse_capture(&capture, 6)?],
+                max: [parse_capture(&capture, 3)?, parse_capture(&capture, 5)?, parse_capture(&capture, 7)?],
+            },
+            state: &capture[1] == "on",
+        })
+    })?;
+
+    Ok(inputs)
 }
 
-pub fn part1(input: &Vec<Instruction>) -> i32 {
+pub fn part1(input: &Vec<Instruction>) -> Result<i32, Error> {
     let mut reactor = vec![vec![vec![false; SIZE]; SIZE]; SIZE];
     
     for instruction in input {
         instruction.apply(&mut reactor);
     }
     
-    reactor.iter().fold(0, |sum1, grid| sum1 + grid.iter().fold(0, |sum2, line| sum2 + line.iter().fold(0, |sum3, &cell| sum3 + if cell { 1 } else { 0 })))
+    Ok(reactor.iter().fold(0, |sum1, grid| sum1 + grid.iter().fold(0, |sum2, line| sum2 + line.iter().fold(0, |sum3, &cell| sum3 + if cell { 1 } else { 0 }))))
 }
 
-pub fn part2(input: &Vec<Instruction>) -> i64 {
+pub fn part2(input: &Vec<Instruction>) -> Result<i64, Error> {
     let all = Zone {
         min: [i32::MIN, i32::MIN, i32::MIN],
         max: [i32::MAX, i32::MAX, i32::MAX],
@@ -43,7 +42,7 @@ pub fn part2(input: &Vec<Instruction>) -> i64 {
 
     let mut cache: HashMap<Id, i64> = HashMap::new();
 
-    count_in_zone(&input.as_slice(), &all, &mut cache)
+    Ok(count_in_zone(&input.as_slice(), &all, &mut cache))
 }
 
 fn count_in_zone(instructions: &[Instruction], zone: &Zone, cache: &mut HashMap<Id, i64>) -> i64 {
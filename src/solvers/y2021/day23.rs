@@ -1,11 +1,12 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::collections::{BinaryHeap, HashSet};
-use std::cmp::Ordering;
+use std::io::BufRead;
 use regex::Regex;
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{self, Display, Formatter};
+use crate::error::Error;
+use crate::helpers::search;
 
-pub fn parser(input_file: io::BufReader<File>) -> Level {
+puzzle!(23, "Amphipod");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Level, Error> {
     let regex = Regex::new(r"#([A-D])#([A-D])#([A-D])#([A-D])#").unwrap();
     let mut level = Level {
         hallway: ['.'; 11],
@@ -14,26 +15,33 @@ pub fn parser(input_file: io::BufReader<File>) -> Level {
     };
     
     let mut room_index = 0;
-    for line in input_file.lines() {
-        if let Ok(ip) = line {
-            if let Option::Some(capture) = regex.captures(&ip) {
-                level.rooms[0][room_index] = capture.get(1).unwrap().as_str().chars().nth(0).unwrap();
-                level.rooms[1][room_index] = capture.get(2).unwrap().as_str().chars().nth(0).unwrap();
-                level.rooms[2][room_index] = capture.get(3).unwrap().as_str().chars().nth(0).unwrap();
-                level.rooms[3][room_index] = capture.get(4).unwrap().as_str().chars().nth(0).unwrap();
-                room_index += 1;
+    for (index, line) in input.lines().enumerate() {
+        let ip = line?;
+        if let Option::Some(capture) = regex.captures(&ip) {
+            if room_index >= level.room_size {
+                return Err(Error::parse(1, "too many amphipods in the rooms").at_line(index + 1));
             }
+
+            level.rooms[0][room_index] = capture.get(1).unwrap().as_str().chars().nth(0).unwrap();
+            level.rooms[1][room_index] = capture.get(2).unwrap().as_str().chars().nth(0).unwrap();
+            level.rooms[2][room_index] = capture.get(3).unwrap().as_str().chars().nth(0).unwrap();
+            level.rooms[3][room_index] = capture.get(4).unwrap().as_str().chars().nth(0).unwrap();
+            room_index += 1;
         }
     }
 
-    level
+    if room_index != level.room_size {
+        return Err(Error::invalid_puzzle(format!("expected {} amphipods per room", level.room_size)));
+    }
+
+    Ok(level)
 }
 
-pub fn part1(input: &Level) -> u32 {
+pub fn part1(input: &Level) -> Result<u32, Error> {
     search(input)
 }
 
-pub fn part2(input: &Level) -> u32 {
+pub fn part2(input: &Level) -> Result<u32, Error> {
     let mut real_input = input.clone();
     real_input.room_size = 4;
 
@@ -54,39 +62,20 @@ pub fn part2(input: &Level) -> u32 {
     search(&real_input)
 }
 
-fn search(level: &Level) -> u32 {
-    let start_pos = Pos::new(level.clone(), 0);
-    
-    let mut close_set: HashSet<Pos> = HashSet::new();
-    let mut open_set: BinaryHeap<Pos> = BinaryHeap::new();
-    open_set.push(start_pos);
-
-    while let Some(node) = open_set.pop() {
-        if node.level.victory() {
-            return node.cost;
-        }
-
-        for m in node.available_moves() {
-            if close_set.contains(&m) {
-                continue;
-            }
-
-            open_set.push(m);
-        }
-
-        close_set.insert(node);
-    }
-
-    u32::MAX
+fn search(level: &Level) -> Result<u32, Error> {
+    search::astar(*level,
+        |level| Pos::new(*level, 0).available_moves().map(|m| (m.level, m.cost)),
+        |level| level.heuristic_to_victory(),
+        |level| level.victory())
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::invalid_puzzle("amphipods cannot be organized"))
 }
 
-#[derive(Eq)]
 #[derive(Copy, Clone)]
-#[derive(Hash)]
+#[derive(PartialEq)]
 struct Pos {
     level: Level,
     cost: u32,
-    heuristic: u32,
 }
 
 impl Pos {
@@ -94,7 +83,6 @@ impl Pos {
         Pos{ 
             level, 
             cost, 
-            heuristic: level.heuristic_to_victory(),
         }
     }
 
@@ -103,24 +91,6 @@ impl Pos {
     }
 }
 
-impl PartialEq for Pos {
-    fn eq(&self, other: &Self) -> bool {
-        self.level == other.level
-    }
-}
-
-impl Ord for Pos {
-    fn cmp(&self, other: &Self) -> Ordering {
-        (self.cost + self.heuristic).cmp(&(other.cost + other.heuristic)).reverse()
-    }
-}
-
-impl PartialOrd for Pos {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 struct IntoMoveIterator {
     starting_pos: Pos,
     room_index: usize,
@@ -332,7 +302,7 @@ impl Level {
 }
 
 impl Display for Level {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         writeln!(f, "#############")?;
         write!(f, "#")?;
         for c in self.hallway {
@@ -1,44 +1,53 @@
-use std::fs::File;
-use std::io;
-use std::fmt::{Display, Formatter, Result};
+use std::io::BufRead;
+use std::fmt::{self, Display, Formatter};
 use std::ops::{AddAssign, MulAssign, DivAssign, RemAssign};
-use regex::Regex;
+use regex::{Captures, Regex};
+use crate::deadline;
+use crate::error::Error;
 use crate::helpers;
 
-pub fn parser(input_file: io::BufReader<File>) -> Vec<Instruction> {
-    let regex = Regex::new(r"^([a-z]+) (w|x|y|z|-?[0-9]+)( (w|x|y|z|-?[0-9]+))?$").unwrap();
-    helpers::parse_file_to_list(input_file, |line| {
-        if let Option::Some(capture) = regex.captures(&line) {
-            let instruction = capture.get(1).unwrap().as_str();
-            return match instruction {
-                "inp" => Instruction::Inp(parse_operand(capture.get(2).unwrap().as_str())),
-                "add" => Instruction::Add(parse_operand(capture.get(2).unwrap().as_str()), parse_operand(capture.get(4).unwrap().as_str())),
-                "mul" => Instruction::Mul(parse_operand(capture.get(2).unwrap().as_str()), parse_operand(capture.get(4).unwrap().as_str())),
-                "div" => Instruction::Div(parse_operand(capture.get(2).unwrap().as_str()), parse_operand(capture.get(4).unwrap().as_str())),
-                "mod" => Instruction::Mod(parse_operand(capture.get(2).unwrap().as_str()), parse_operand(capture.get(4).unwrap().as_str())),
-                "eql" => Instruction::Eql(parse_operand(capture.get(2).unwrap().as_str()), parse_operand(capture.get(4).unwrap().as_str())),
-                _ => panic!("Unknown instruction {}", instruction),
+puzzle!(24, "Arithmetic Logic Unit");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<Vec<Instruction>, Error> {
+    let regex = Regex::new(r"^([a-z]+) (w|x|y|z)( (w|x|y|z|-?[0-9]+))?$").unwrap();
+    helpers::parse_file_to_list(input, |line| {
+        let capture = helpers::parse_regex(&regex, line)?;
+        let instruction = &capture[1];
+        if instruction == "inp" {
+            if capture.get(4).is_some() {
+                return Err(Error::parse(capture.get(3).unwrap().start() + 1, "inp takes a single operand"));
             }
-        } else {
-            panic!("Unknown instruction {}", line);
+
+            return Ok(Instruction::Inp(parse_operand(&capture, 2)?));
+        }
+
+        let (a, b) = (parse_operand(&capture, 2)?, parse_operand(&capture, 4)?);
+        match instruction {
+            "add" => Ok(Instruction::Add(a, b)),
+            "mul" => Ok(Instruction::Mul(a, b)),
+            "div" => Ok(Instruction::Div(a, b)),
+            "mod" => Ok(Instruction::Mod(a, b)),
+            "eql" => Ok(Instruction::Eql(a, b)),
+            _ => Err(Error::parse(1, format!("unknown instruction '{}'", instruction))),
         }
     })
 }
 
-fn parse_operand(input: &str) -> Operand {
-    match input {
-        "w" => Operand::Variable(0),
-        "x" => Operand::Variable(1),
-        "y" => Operand::Variable(2),
-        "z" => Operand::Variable(3),
-        _ => Operand::Number(input.parse().unwrap()),
+fn parse_operand(capture: &Captures, index: usize) -> Result<Operand, Error> {
+    match capture.get(index).map(|operand| operand.as_str()) {
+        Some("w") => Ok(Operand::Variable(0)),
+        Some("x") => Ok(Operand::Variable(1)),
+        Some("y") => Ok(Operand::Variable(2)),
+        Some("z") => Ok(Operand::Variable(3)),
+        _ => Ok(Operand::Number(helpers::parse_capture(capture, index)?)),
     }
 }
 
-pub fn part1(input: &Vec<Instruction>) -> i64 {
+pub fn part1(input: &Vec<Instruction>) -> Result<i64, Error> {
     let mut context = Context::new([9i32; 14], 1);
 
     loop {
+        deadline::check()?;
         context.stdinptr = 0;
         context.reset_memory();
         for instruction in input {
@@ -66,13 +75,14 @@ pub fn part1(input: &Vec<Instruction>) -> i64 {
         }
     }
 
-    context.stdin_as_number()
+    Ok(context.stdin_as_number())
 }
 
-pub fn part2(input: &Vec<Instruction>) -> i64 {
+pub fn part2(input: &Vec<Instruction>) -> Result<i64, Error> {
     let mut context = Context::new([1i32; 14], 1);
 
     loop {
+        deadline::check()?;
         context.stdinptr = 0;
         context.reset_memory();
         for instruction in input {
@@ -108,7 +118,7 @@ pub fn part2(input: &Vec<Instruction>) -> i64 {
         }
     }
 
-    context.stdin_as_number()
+    Ok(context.stdin_as_number())
 }
 
 #[derive(Copy, Clone)]
@@ -160,7 +170,7 @@ impl Context {
 }
 
 impl Display for Context {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         writeln!(f, "stdin: {}", self.stdin_as_number())?;
         writeln!(f, "w: {}", self.memory[0].to_string())?;
         writeln!(f, "x: {}", self.memory[1].to_string())?;
@@ -308,7 +318,7 @@ impl RemAssign for Memory {
 }
 
 impl Display for Memory {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Memory::Value(value) => write!(f, "{}", value.to_string())?,
             Memory::Range(start, end) => write!(f, "{}..{}", start.to_string(), end.to_string())?,
@@ -0,0 +1,55 @@
+use std::io::BufRead;
+use std::collections::HashSet;
+use crate::Answer;
+use crate::error::Error;
+use crate::helpers;
+
+puzzle!(25, "Sea Cucumber");
+
+pub fn parser(input: &mut dyn BufRead) -> Result<helpers::Map<char>, Error> {
+    let map = helpers::Map::parse_grid(input, |c| match c {
+        '>' | 'v' | '.' => Ok(c),
+        _ => Err(String::from("expected '>', 'v' or '.'")),
+    })?;
+
+    // The cucumbers leaving the map reappear on the other side
+    Ok(map.with_boundary(helpers::Boundary::Wrap))
+}
+
+pub fn part1(input: &helpers::Map<char>) -> Result<u32, Error> {
+    let mut map = input.clone();
+    let mut cache: HashSet<helpers::Position> = HashSet::new();
+
+    let mut step = 0;
+    while do_step(&mut map, &mut cache) {
+        step += 1;
+    }
+    
+    Ok(step + 1)
+}
+
+pub fn part2(_: &helpers::Map<char>) -> Result<Answer, Error> {
+    Ok(Answer::NotApplicable)
+}
+
+fn do_step(map: &mut helpers::Map<char>, cache: &mut HashSet<helpers::Position>) -> bool {
+    let mut at_least_one_move = false;
+    for i in 0..2 {
+        let (cucumber, direction) = if i == 0 { ('>', helpers::Position::new(1, 0)) } else { ('v', helpers::Position::new(0, 1)) };
+
+        cache.clear();
+        for (position, &cell) in map.cells() {
+            if cell == cucumber && map.get(position + direction) == Some(&'.') {
+                cache.insert(position);
+            }
+        }
+    
+        for position in cache.iter() {
+            map.set(*position, '.');
+            map.set(*position + direction, cucumber);
+            at_least_one_move = true;
+        }
+    }
+
+    at_least_one_move
+}
@@ -1,211 +1,398 @@
 #[cfg(test)]
 mod tests {
-    use std::io;
-    use std::path::Path;
-    use std::fs::File;
-    use crate::{DaySolver, Solver};
-    use crate::solvers::*;
+    use std::io::{self, BufRead, Read, Write};
+    use std::fs::{self, File};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+    use crate::{get_puzzle, puzzles, solve, years, Answer, DaySolver, Puzzle, Solver};
+    use crate::answers::{Answers, Verdict};
+    use crate::answers;
+    use crate::client::{Client, Outcome};
+    use crate::guesses::Guesses;
+    use crate::error::Error;
+    use crate::{deadline, helpers, ocr};
+    use crate::helpers::search;
+    use crate::inputs::{self, DEFAULT_INPUT};
+    use crate::solvers::y2021::*;
 
-    fn open_test_file(day: i32) -> io::BufReader<std::fs::File> {
-        let path = Path::new("data").join(format!("day{:02}.txt", day));
-        io::BufReader::new(File::open(&path).expect("Failed to read file"))
-    }
+    /// Solve a named input of a puzzle and compare with the expected answers.
+    fn check_answers(puzzle: &Puzzle, input: &str, answers: &Answers) -> Result<(), String> {
+        let (year, day) = (puzzle.year, puzzle.day);
+        let context = format!("{} day {} {}", year, day, input);
+        let file = File::open(inputs::input_path(year, day, input)).map_err(|error| format!("{}: {}", context, error))?;
+        let mut solver = (puzzle.solver)();
+        solver.parse(&mut io::BufReader::new(file)).map_err(|error| format!("{}: {}", context, error))?;
 
-    #[test]
-    fn day01() {
-        let mut solver = DaySolver::from(day01::parser, day01::part1, day01::part2);
-        solver.parse(open_test_file(1));
-        assert_eq!(solver.solve_part1(), 1162, "Part1");
-        assert_eq!(solver.solve_part2(), 1190, "Part2");
-    }
+        let mut checked = 0;
+        for part in 1..=2 {
+            if let Some(expected) = answers.expected(day, input, part) {
+                let answer = if part == 1 { solver.part1() } else { solver.part2() };
+                let answer = answer.map_err(|error| format!("{} part {}: {}", context, part, error))?.to_string();
+                if answer.trim() != expected.trim() {
+                    return Err(format!("{} part {}: expected {}, got {}", context, part, expected.trim(), answer.trim()));
+                }
+                checked += 1;
+            }
+        }
 
-    #[test]
-    fn day02() {
-        let mut solver = DaySolver::from(day02::parser, day02::part1, day02::part2);
-        solver.parse(open_test_file(2));
-        assert_eq!(solver.solve_part1(), 1648020, "Part1");
-        assert_eq!(solver.solve_part2(), 1759818555, "Part2");
-    }
-    
-    #[test]
-    fn day03() {
-        let mut solver = DaySolver::from(day03::parser, day03::part1, day03::part2);
-        solver.parse(open_test_file(3));
-        assert_eq!(solver.solve_part1(), 3901196, "Part1");
-        assert_eq!(solver.solve_part2(), 4412188, "Part2");
+        if checked == 0 && !answers.is_pending(day) {
+            return Err(format!("{}: no expected answer", context));
+        }
+        Ok(())
     }
 
+    /// Every input of every registered puzzle is solved, in parallel, and checked against data/<year>/answers.toml.
+    /// The pending days may miss their input and answers.
     #[test]
-    fn day04() {
-        let mut solver = DaySolver::from(day04::parser, day04::part1, day04::part2);
-        solver.parse(open_test_file(4));
-        assert_eq!(solver.solve_part1(), 8442, "Part1");
-        assert_eq!(solver.solve_part2(), 4590, "Part2");
-    }
+    fn answers() -> Result<(), Error> {
+        let answers = years()
+            .map(|year| Ok((year, Answers::load(inputs::answers_path(year))?)))
+            .collect::<Result<Vec<(u16, Answers)>, Error>>()?;
 
-    #[test]
-    fn day05() {
-        let mut solver = DaySolver::from(day05::parser, day05::part1, day05::part2);
-        solver.parse(open_test_file(5));
-        assert_eq!(solver.solve_part1(), 6267, "Part1");
-        assert_eq!(solver.solve_part2(), 20196, "Part2");
-    }
+        let mut cases = Vec::new();
+        for (year, answers) in &answers {
+            for puzzle in puzzles(*year)? {
+                let input_names = inputs::input_names(*year, puzzle.day)?;
+                let has_input = input_names.iter().any(|name| name == DEFAULT_INPUT);
+                assert!(has_input || answers.is_pending(puzzle.day), "No input for {} day {}", year, puzzle.day);
+                cases.extend(input_names.into_iter().map(|name| (puzzle, name, answers)));
+            }
+        }
 
-    #[test]
-    fn day06() {
-        let mut solver = DaySolver::from(day06::parser, day06::part1, day06::part2);
-        solver.parse(open_test_file(6));
-        assert_eq!(solver.solve_part1(), 380758, "Part1");
-        assert_eq!(solver.solve_part2(), 1710623015163, "Part2");
-    }
+        let failures: Vec<String> = thread::scope(|scope| {
+            let handles: Vec<_> = cases.iter()
+                .map(|(puzzle, name, answers)| scope.spawn(move || check_answers(puzzle, name, answers)))
+                .collect();
+            handles.into_iter()
+                .filter_map(|handle| handle.join().expect("Solver panicked").err())
+                .collect()
+        });
 
-    #[test]
-    fn day07() {
-        let mut solver = DaySolver::from(day07::parser, day07::part1, day07::part2);
-        solver.parse(open_test_file(7));
-        assert_eq!(solver.solve_part1(), 349769, "Part1");
-        assert_eq!(solver.solve_part2(), 99540554, "Part2");
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        Ok(())
     }
 
     #[test]
-    fn day08() {
-        let mut solver = DaySolver::from(day08::parser, day08::part1, day08::part2);
-        solver.parse(open_test_file(8));
-        assert_eq!(solver.solve_part1(), 488, "Part1");
-        assert_eq!(solver.solve_part2(), 1040429, "Part2");
+    fn solve_api() -> Result<(), Error> {
+        let input = std::fs::read_to_string(inputs::input_path(2021, 1, DEFAULT_INPUT))?;
+        assert_eq!(solve(2021, 1, 1, &input)?, Answer::Integer(1162), "Part1");
+        assert_eq!(solve(2021, 1, 2, &input)?, Answer::Integer(1190), "Part2");
+        assert!(matches!(solve(2014, 1, 1, &input), Err(Error::UnknownYear(2014))), "Unknown year");
+        assert!(matches!(solve(2021, 26, 1, &input), Err(Error::UnknownDay(26))), "Unknown day");
+        assert!(matches!(solve(2021, 1, 3, &input), Err(Error::UnknownPart(3))), "Unknown part");
+        Ok(())
     }
 
     #[test]
-    fn day09() {
-        let mut solver = DaySolver::from(day09::parser, day09::part1, day09::part2);
-        solver.parse(open_test_file(9));
-        assert_eq!(solver.solve_part1(), 560, "Part1");
-        assert_eq!(solver.solve_part2(), 959136, "Part2");
+    fn registry() -> Result<(), Error> {
+        assert!(years().any(|year| year == 2021), "Registered years");
+        let puzzles = puzzles(2021)?;
+        assert_eq!(puzzles.len(), 25, "Registered puzzles");
+        assert!(puzzles.iter().enumerate().all(|(index, puzzle)| puzzle.year == 2021 && puzzle.day as usize == index + 1), "Puzzles in order");
+        let puzzle = get_puzzle(2021, 13)?;
+        assert_eq!(puzzle.title, "Transparent Origami");
+        assert_eq!(puzzle.answer_types(), ["usize", "Answer"]);
+        assert_eq!(get_puzzle(2021, 4)?.answer_types(), ["Option<i32>", "Option<i32>"]);
+        assert_eq!(crate::short_type_name::<Vec<(String, Option<std::path::PathBuf>)>>(), "Vec<(String, Option<PathBuf>)>", "Paths of the type parameters");
+        assert!(matches!(get_puzzle(2021, 26), Err(Error::UnknownDay(26))), "Unknown day");
+        Ok(())
     }
 
     #[test]
-    fn day10() {
-        let mut solver = DaySolver::from(day10::parser, day10::part1, day10::part2);
-        solver.parse(open_test_file(10));
-        assert_eq!(solver.solve_part1(), 299793, "Part1");
-        assert_eq!(solver.solve_part2(), 3654963618, "Part2");
-    }
+    fn answers_file() -> Result<(), Error> {
+        let answers: Answers = "[day01]\npart1 = 7\npart2 = \"\"\"\n#.#\n\"\"\"\n[day01.example]\npart1 = 3\n".parse()?;
+        assert_eq!(answers.check(1, DEFAULT_INPUT, 1, &Answer::Integer(7)), Verdict::Pass, "Integer answer");
+        assert_eq!(answers.check(1, DEFAULT_INPUT, 1, &Answer::Integer(8)), Verdict::Fail, "Wrong answer");
+        assert_eq!(answers.check(1, DEFAULT_INPUT, 2, &Answer::Grid(vec![String::from("#.#")])), Verdict::Pass, "Grid answer");
+        assert_eq!(answers.check(1, "example", 1, &Answer::Integer(3)), Verdict::Pass, "Named input");
+        assert_eq!(answers.check(1, "example", 2, &Answer::Integer(3)), Verdict::Unknown, "Missing answer of a named input");
+        assert_eq!(answers.check(2, DEFAULT_INPUT, 1, &Answer::Integer(7)), Verdict::Unknown, "Missing answer");
+        assert_eq!(answers.check(2, DEFAULT_INPUT, 2, &Answer::NotApplicable), Verdict::Pass, "Part without puzzle");
+        assert!(matches!("[day01]\npart3 = 7\n".parse::<Answers>(), Err(Error::InvalidAnswers(_))), "Unknown part");
 
-    #[test]
-    fn day11() {
-        let mut solver = DaySolver::from(day11::parser, day11::part1, day11::part2);
-        solver.parse(open_test_file(11));
-        assert_eq!(solver.solve_part1(), 1702, "Part1");
-        assert_eq!(solver.solve_part2(), 251, "Part2");
+        let answers: Answers = "[day02]\npending = true\npart1 = 5\n".parse()?;
+        assert!(answers.is_pending(2) && !answers.is_pending(1), "Pending day");
+        assert_eq!(answers.expected(2, DEFAULT_INPUT, 1), Some("5"));
+        Ok(())
     }
 
     #[test]
-    fn day12() {
-        let mut solver = DaySolver::from(day12::parser, day12::part1, day12::part2);
-        solver.parse(open_test_file(12));
-        assert_eq!(solver.solve_part1(), 4720, "Part1");
-        assert_eq!(solver.solve_part2(), 147848, "Part2");
+    fn answer_kinds() {
+        assert_eq!(Answer::from(42u32), Answer::Integer(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(None::<i32>), Answer::NoSolution);
+        assert_eq!(Answer::from(Some(-1)), Answer::Integer(-1));
+        assert_eq!(Answer::Grid(vec![String::from("#."), String::from(".#")]).to_string(), "#.\n.#");
+        assert_eq!(serde_json::to_string(&Answer::Integer(7)).unwrap(), r#"{"kind":"integer","value":7}"#);
+        assert_eq!(serde_json::to_string(&Answer::NotApplicable).unwrap(), r#"{"kind":"not_applicable"}"#);
     }
 
-    #[test]
-    fn day13() {
-        let mut solver = DaySolver::from(day13::parser, day13::part1, day13::part2);
-        solver.parse(open_test_file(13));
-        assert_eq!(solver.solve_part1(), 775, "Part1");
+    fn map_from_rows(rows: &[&str]) -> helpers::Map<char> {
+        rows.join("\n").parse().unwrap()
     }
 
     #[test]
-    fn day14() {
-        let mut solver = DaySolver::from(day14::parser, day14::part1, day14::part2);
-        solver.parse(open_test_file(14));
-        assert_eq!(solver.solve_part1(), 2027, "Part1");
-        assert_eq!(solver.solve_part2(), 2265039461737, "Part2");
+    fn ocr_letters() {
+        let letters = map_from_rows(&[
+            "..........",
+            "#..#.###..",
+            "#..#.#..#.",
+            "####.###..",
+            "#..#.#..#.",
+            "#..#.#..#.",
+            "#..#.###..",
+        ]);
+        assert_eq!(ocr::read_letters(&letters), Some(String::from("HB")), "Letters");
+
+        let wide_letter = map_from_rows(&[
+            "#...#.#..#",
+            "#...#.#..#",
+            ".#.#..####",
+            "..#...#..#",
+            "..#...#..#",
+            "..#...#..#",
+        ]);
+        assert_eq!(ocr::read_letters(&wide_letter), Some(String::from("YH")), "Letter after a wide letter");
+
+        let square = map_from_rows(&["#####", "#...#", "#...#", "#...#", "#####", "....."]);
+        assert_eq!(ocr::read_letters(&square), None, "Not letters");
+        assert_eq!(ocr::read_letters(&map_from_rows(&["....", "...."])), None, "Empty map");
     }
 
     #[test]
-    fn day15() {
-        let mut solver = DaySolver::from(day15::parser, day15::part1, day15::part2);
-        solver.parse(open_test_file(15));
-        assert_eq!(solver.solve_part1(), 415, "Part1");
-        assert_eq!(solver.solve_part2(), 2864, "Part2");
+    fn parse_error_location() {
+        let mut solver = DaySolver::from(day05::parser, day05::part1, day05::part2);
+        match solver.parse(&mut "0,9 -> 5,9\n8,0 -> 0,99999999999\n".as_bytes()) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 10)),
+            _ => panic!("Expected a parse error"),
+        }
+
+        let mut solver = DaySolver::from(day20::parser, day20::part1, day20::part2);
+        match solver.parse(&mut format!("{}\n\n#..\n.x.\n", "#".repeat(512)).as_bytes()) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (4, 2)),
+            _ => panic!("Expected a parse error"),
+        }
     }
 
     #[test]
-    fn day16() {
+    fn malformed_inputs() {
+        let mut solver = DaySolver::from(day03::parser, day03::part1, day03::part2);
+        assert!(matches!(solver.parse(&mut "\n\n".as_bytes()), Err(Error::Parse { line: 1, column: 1, .. })), "Blank report");
+        assert!(solver.parse(&mut "01\n01\n".as_bytes()).is_ok());
+        assert!(matches!(solver.part2(), Err(Error::InvalidPuzzle(_))), "Duplicated ratings");
+
         let mut solver = DaySolver::from(day16::parser, day16::part1, day16::part2);
-        solver.parse(open_test_file(16));
-        assert_eq!(solver.solve_part1(), 1012, "Part1");
-        assert_eq!(solver.solve_part2(), 2223947372407, "Part2");
+        assert!(solver.parse(&mut "16002208".as_bytes()).is_ok());
+        assert!(matches!(solver.part2(), Err(Error::Parse { line: 1, column: 1, .. })), "Comparison of a single packet");
+        assert!(solver.parse(&mut "0000745801102".as_bytes()).is_ok());
+        assert!(matches!(solver.part2(), Err(Error::Parse { line: 1, column: 6, .. })), "Nested comparison of a single packet");
+
+        let beacons: String = (0..12).map(|i| format!("{},{},{}\n", i, i * i, -i)).collect();
+        let mut solver = DaySolver::from(day19::parser, day19::part1, day19::part2);
+        assert!(solver.parse(&mut format!("--- scanner 1 ---\n{}\n\n", beacons).as_bytes()).is_ok(), "Trailing empty lines");
+        assert!(matches!(solver.part1(), Err(Error::InvalidPuzzle(message)) if message == "1 scanners cannot be located"), "Without scanner 0");
     }
 
     #[test]
-    fn day17() {
-        let mut solver = DaySolver::from(day17::parser, day17::part1, day17::part2);
-        solver.parse(open_test_file(17));
-        assert_eq!(solver.solve_part1(), 3655, "Part1");
-        assert_eq!(solver.solve_part2(), 1447, "Part2");
+    fn parse_grid() -> Result<(), Error> {
+        let map: helpers::Map<u8> = "\n123\n456\n\n".parse()?;
+        assert_eq!((map.width, map.height), (3, 2));
+        assert_eq!(map.get(helpers::Position::new(2, 1)), Some(&6));
+
+        let grid = |input: &str| helpers::Map::parse_grid(input.as_bytes(), |c| if c == '#' || c == '.' { Ok(c == '#') } else { Err(String::from("expected '#' or '.'")) });
+        assert_eq!(grid("#.\n.#\n")?.iter().filter(|&&lit| lit).count(), 2);
+        assert!(matches!(grid("#.\n.#.\n"), Err(Error::Parse { line: 2, column: 1, .. })), "Uneven rows");
+        assert!(matches!(grid("#.\n.o\n"), Err(Error::Parse { line: 2, column: 2, .. })), "Invalid cell");
+        assert!(matches!(grid("#.\n\n.#\n"), Err(Error::Parse { line: 3, column: 1, .. })), "Two grids");
+        assert!(matches!("12\n3a".parse::<helpers::Map<u32>>(), Err(Error::Parse { line: 2, column: 2, .. })), "Not a digit");
+        Ok(())
     }
 
     #[test]
-    fn day18() {
-        let mut solver = DaySolver::from(day18::parser, day18::part1, day18::part2);
-        solver.parse(open_test_file(18));
-        assert_eq!(solver.solve_part1(), 4137, "Part1");
-        assert_eq!(solver.solve_part2(), 4573, "Part2");
+    fn map_transforms() {
+        let map = map_from_rows(&["abc", "def"]);
+        let text = |map: &helpers::Map<char>| map.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>();
+        assert_eq!(map.positions().nth(4), Some(helpers::Position::new(1, 1)), "Row by row");
+        assert_eq!(map.cells().find(|(_, &c)| c == 'f').map(|(pos, _)| pos), Some(helpers::Position::new(2, 1)));
+        assert_eq!(map.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+        assert_eq!(text(&map.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(text(&map.flip_horizontal()), ["cba", "fed"]);
+        assert_eq!(text(&map.flip_vertical()), ["def", "abc"]);
+        assert_eq!(text(&map.rotate_right()), ["da", "eb", "fc"]);
+        assert_eq!(text(&map.rotate_left()), ["cf", "be", "ad"]);
+        assert_eq!(text(&map.crop(helpers::Position::new(1, 0), 5, 1)), ["bc"], "Crop limited to the map");
+
+        let view = map.view(helpers::Position::new(1, 1), 2, 2);
+        assert_eq!((view.width, view.height), (2, 1));
+        assert_eq!(view.get(helpers::Position::new(1, 0)), Some(&'f'));
+        assert_eq!(view.get(helpers::Position::new(0, 1)), None);
+        assert_eq!(view.cells().map(|(_, &c)| c).collect::<String>(), "ef");
+
+        let view = map.view(helpers::Position::new(-1, -1), 2, 2);
+        assert_eq!((view.width, view.height), (1, 1), "Only the part of the view inside the map");
+        assert_eq!(view.get(helpers::Position::new(0, 0)), Some(&'a'));
+        assert_eq!(text(&map.crop(helpers::Position::new(-2, 1), 4, 3)), ["de"]);
+        assert_eq!(map.view(helpers::Position::new(-3, 0), 2, 2).width, 0, "Outside of the map");
     }
 
     #[test]
-    fn day19() {
-        let mut solver = DaySolver::from(day19::parser, day19::part1, day19::part2);
-        solver.parse(open_test_file(19));
-        assert_eq!(solver.solve_part1(), 383, "Part1");
-        assert_eq!(solver.solve_part2(), 9854, "Part2");
+    fn map_boundaries() {
+        let map = map_from_rows(&["ab", "cd"]);
+        let at = |x, y| helpers::Position::new(x, y);
+        assert_eq!(map.get(at(2, 0)), None, "Bounded");
+        assert_eq!(map.neighbours(at(0, 0), false).count(), 2);
+
+        let wrapped = map.clone().with_boundary(helpers::Boundary::Wrap);
+        assert_eq!((wrapped.get(at(2, 0)), wrapped.get(at(-1, -1))), (Some(&'a'), Some(&'d')));
+        assert_eq!(wrapped.neighbours(at(0, 0), false).map(|(pos, _)| pos).collect::<Vec<_>>(), [at(1, 0), at(0, 1), at(1, 0), at(0, 1)]);
+
+        let constant = map.clone().with_boundary(helpers::Boundary::Constant('.'));
+        assert_eq!(constant.get(at(5, -3)), Some(&'.'));
+        assert_eq!(constant.neighbours(at(0, 0), true).filter(|(_, &c)| c == '.').count(), 5);
+
+        let mut grown = map.with_boundary(helpers::Boundary::Grow('.'));
+        grown.set(at(-1, 2), '#');
+        assert_eq!((grown.width, grown.height, grown.origin()), (3, 3, at(-1, 0)));
+        assert_eq!(grown.to_string(), ".ab\n.cd\n#..\n");
+        assert_eq!(grown.positions().next(), Some(at(-1, 0)));
     }
 
     #[test]
-    fn day20() {
-        let mut solver = DaySolver::from(day20::parser, day20::part1, day20::part2);
-        solver.parse(open_test_file(20));
-        assert_eq!(solver.solve_part1(), 5419, "Part1");
-        assert_eq!(solver.solve_part2(), 17325, "Part2");
+    fn sparse_map() {
+        let at = |x, y| helpers::Position::new(x, y);
+        let mut map = helpers::SparseMap::new();
+        assert_eq!((map.bounds(), map.to_string()), (None, String::new()));
+
+        map.set(at(2, -1), '#');
+        *map.get_or_insert_default(at(0, 1)) = 'o';
+        assert_eq!(map.bounds(), Some((at(0, -1), at(2, 1))), "Bounds include a cell only set after being missing");
+        assert_eq!(map.to_string(), "..#\n...\no..\n");
+
+        let dense = map.to_map(' ');
+        assert_eq!((dense.width, dense.height, dense.origin()), (3, 3, at(0, -1)));
+        assert_eq!(dense.get(at(2, -1)), Some(&'#'));
+
+        let sparse: helpers::SparseMap<char> = map_from_rows(&["#.", ".#"]).cells().filter(|(_, &c)| c == '#').map(|(pos, &c)| (pos, c)).collect();
+        assert_eq!((sparse.len(), sparse.get(at(1, 1)), sparse.get(at(1, 0))), (2, Some(&'#'), None));
+        assert_eq!(helpers::SparseMap::from(&map_from_rows(&["#.", ".#"])).len(), 4);
     }
 
     #[test]
-    fn day21() {
-        let mut solver = DaySolver::from(day21::parser, day21::part1, day21::part2);
-        solver.parse(open_test_file(21));
-        assert_eq!(solver.solve_part1(), 1004670, "Part1");
-        assert_eq!(solver.solve_part2(), 492043106122795, "Part2");
+    fn search() {
+        let map = map_from_rows(&["S.#.", "1#..", "9..E"]);
+        let at = |x, y| helpers::Position::new(x, y);
+        let open = |pos: &helpers::Position| map.neighbours(*pos, false).filter(|(_, &c)| c != '#').map(|(neighbour, _)| neighbour).collect::<Vec<_>>();
+        let steps = |pos: &helpers::Position| open(pos).into_iter().map(|neighbour| (neighbour, map.get(neighbour).unwrap().to_digit(10).unwrap_or(1)));
+        let is_exit = |pos: &helpers::Position| map.get(*pos) == Some(&'E');
+
+        let path = search::bfs(at(0, 0), open, is_exit).unwrap();
+        assert_eq!((path.cost, path.states.len()), (5, 6));
+        assert_eq!((path.states.first(), path.states.last()), (Some(&at(0, 0)), Some(&at(3, 2))));
+
+        let path = search::dijkstra(at(0, 0), steps, is_exit).unwrap();
+        assert_eq!(path.cost, 13, "Through the only way to the exit");
+        assert!(path.states.windows(2).all(|step| open(&step[0]).contains(&step[1])));
+        let heuristic = |pos: &helpers::Position| (3 - pos.x + 2 - pos.y) as u32;
+        assert_eq!(search::astar(at(0, 0), steps, heuristic, is_exit).map(|path| path.cost), Some(13));
+
+        assert_eq!(search::flood_fill(at(0, 0), open).len(), 10);
+        assert_eq!(search::bfs(at(0, 0), open, |&pos| pos == at(2, 0)), None, "Wall");
     }
 
     #[test]
-    fn day22() {
-        let mut solver = DaySolver::from(day22::parser, day22::part1, day22::part2);
-        solver.parse(open_test_file(22));
-        assert_eq!(solver.solve_part1(), 603661, "Part1");
-        assert_eq!(solver.solve_part2(), 1237264238382479, "Part2");
+    fn timeout() -> Result<(), Error> {
+        let mut solver = DaySolver::from(day11::parser, day11::part1, day11::part2);
+        solver.parse(&mut io::BufReader::new(File::open(inputs::input_path(2021, 11, DEFAULT_INPUT))?))?;
+
+        deadline::start(Some(Duration::ZERO));
+        let result = solver.part2();
+        deadline::start(None);
+        assert!(matches!(result, Err(Error::Timeout(Duration::ZERO))), "Spent budget");
+        assert!(solver.part2().is_ok(), "No budget");
+        Ok(())
     }
 
-    #[test]
-    fn day23() {
-        let mut solver = DaySolver::from(day23::parser, day23::part1, day23::part2);
-        solver.parse(open_test_file(23));
-        assert_eq!(solver.solve_part1(), 15299, "Part1");
-        assert_eq!(solver.solve_part2(), 47193, "Part2");
+    /// Serve `count` requests with the same response, and return their request lines, cookies and bodies.
+    fn stub_server(count: usize, response: &'static str) -> (String, thread::JoinHandle<Vec<(String, String, String)>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            listener.incoming().take(count).map(|stream| {
+                let mut stream = stream.unwrap();
+                let mut reader = io::BufReader::new(&stream);
+                let headers: Vec<String> = reader.by_ref().lines().map(Result::unwrap).take_while(|line| !line.is_empty()).collect();
+                let header = |name: &str| headers.iter().find_map(|line| line.strip_prefix(name).map(String::from)).unwrap_or_default();
+                let mut body = vec![0; header("Content-Length: ").parse().unwrap_or(0)];
+                reader.read_exact(&mut body).unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+                (headers[0].clone(), header("Cookie: "), String::from_utf8(body).unwrap())
+            }).collect()
+        });
+        (base_url, server)
     }
-    
+
     #[test]
-    fn day24() {
-        let mut solver = DaySolver::from(day24::parser, day24::part1, day24::part2);
-        solver.parse(open_test_file(24));
-        assert_eq!(solver.solve_part1(), 99911993949684, "Part1");
-        assert_eq!(solver.solve_part2(), 62911941716111, "Part2");
+    fn fetch_input() -> Result<(), Error> {
+        let (base_url, server) = stub_server(1, "HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n1\n2\n3\n");
+        let client = Client::new(&format!("{}/", base_url), "secret\n");
+        let path = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id())).join("input.txt");
+
+        assert!(inputs::fetch_input(&client, 2021, 1, &path)?, "Downloaded");
+        assert!(!inputs::fetch_input(&client, 2021, 1, &path)?, "Cached");
+        assert_eq!(fs::read_to_string(&path)?, "1\n2\n3\n");
+        assert_eq!(server.join().unwrap(), [(String::from("GET /2021/day/1/input HTTP/1.1"), String::from("session=secret"), String::new())]);
+        fs::remove_dir_all(path.parent().unwrap())?;
+
+        let (base_url, server) = stub_server(1, "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        assert!(matches!(Client::new(&base_url, "secret").input(2021, 25), Err(Error::Fetch(_))), "Not released");
+        server.join().unwrap();
+        Ok(())
     }
 
     #[test]
-    fn day25() {
-        let mut solver = DaySolver::from(day25::parser, day25::part1, day25::part2);
-        solver.parse(open_test_file(25));
-        assert_eq!(solver.solve_part1(), 498, "Part1");
+    fn submit_answer() -> Result<(), Error> {
+        let page = |message: &str| format!("<main>\n<article><p>{}  <a href=\"/2021/day/5\">[Return to Day 5]</a></p></article>\n</main>", message);
+        assert_eq!(Outcome::parse(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")), Outcome::Correct);
+        assert_eq!(Outcome::parse(&page("That's not the right answer; your answer is too high.")), Outcome::TooHigh);
+        assert_eq!(Outcome::parse(&page("That's not the right answer; your answer is too low.")), Outcome::TooLow);
+        assert_eq!(Outcome::parse(&page("That's not the right answer.")), Outcome::Wrong);
+        assert_eq!(Outcome::parse(&page("You gave an answer too recently.  You have 1m 5s left to wait.")), Outcome::Wait(Some(Duration::from_secs(65))));
+        assert_eq!(Outcome::parse(&page("You don't seem to be solving the right level.")), Outcome::WrongLevel);
+
+        let (base_url, server) = stub_server(1, "HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n<article><p>That's not the right answer.</p></article>");
+        assert_eq!(Client::new(&base_url, "secret").submit(2021, 5, 2, "42")?, Outcome::Wrong);
+        assert_eq!(server.join().unwrap()[0].2, "level=2&answer=42");
+
+        let mut guesses = Guesses::default();
+        guesses.record(5, 1, "7000", &Outcome::TooHigh);
+        guesses.record(5, 1, "100", &Outcome::TooLow);
+        guesses.record(5, 1, "6000", &Outcome::Wrong);
+        assert!(!guesses.record(5, 1, "6267", &Outcome::Correct), "Right answer");
+        let guesses: Guesses = toml::from_str(&toml::to_string(&guesses).unwrap()).unwrap();
+        assert!(guesses.check(5, 1, "6000").is_some(), "Wrong answer");
+        assert!(guesses.check(5, 1, "8000").is_some(), "Too high");
+        assert!(guesses.check(5, 1, "100").is_some(), "Too low");
+        assert_eq!(guesses.check(5, 1, "6267"), None);
+        assert_eq!(guesses.check(5, 2, "6000"), None);
+
+        let guesses_path = std::env::temp_dir().join(format!("aoc-guesses-{}.toml", std::process::id()));
+        fs::write(&guesses_path, "[day05.part1]\nwrong = 7\n")?;
+        assert!(matches!(Guesses::load(&guesses_path), Err(Error::InvalidGuesses { path, .. }) if path == guesses_path), "Invalid guesses file");
+        fs::remove_file(&guesses_path)?;
+
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        fs::write(&path, "[day01]\npart1 = 1162\n\n[day01.example]\npart1 = 7\n")?;
+        answers::save_answer(&path, 1, 2, "1190")?;
+        answers::save_answer(&path, 13, 2, "REUPUPKR")?;
+        answers::save_pending(&path, 14)?;
+        answers::save_pending(&path, 15)?;
+        answers::save_answer(&path, 15, 1, "2864")?;
+        let saved = Answers::load(&path)?;
+        fs::remove_file(&path)?;
+        assert_eq!((saved.expected(1, DEFAULT_INPUT, 2), saved.expected(1, "example", 1)), (Some("1190"), Some("7")));
+        assert_eq!(saved.expected(13, DEFAULT_INPUT, 2), Some("REUPUPKR"));
+        assert!(saved.is_pending(14) && !saved.is_pending(15), "A saved answer clears the pending mark");
+        Ok(())
     }
 }